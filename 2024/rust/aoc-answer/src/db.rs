@@ -0,0 +1,183 @@
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::Answer;
+
+/// Overrides where the answers are stored, instead of `answers.json` next to the puzzle inputs.
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+
+/// The known-good answers for every day and part, stored as JSON keyed by day and then part:
+///
+/// ```json
+/// { "1": { "1": 1882714, "2": 19437052 }, "17": { "1": "7,3,5,7,5,7,4,3,0" } }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AnswerDb {
+    answers: BTreeMap<u8, BTreeMap<u8, Answer>>,
+}
+
+/// How an answer compares to the one on record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Answer,
+    },
+    /// Nothing is recorded for this day and part yet.
+    Unknown,
+}
+
+impl AnswerDb {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `AOC_ANSWERS` when it is set, otherwise `answers.json` in `input_dir`.
+    pub fn default_path(input_dir: &Path) -> PathBuf {
+        env::var_os(ANSWERS_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| input_dir.join("answers.json"))
+    }
+
+    /// Read the answers at `path`, treating a missing file as an empty database.
+    pub fn load(path: &Path) -> Result<Self, AnswerDbError> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::new()),
+            Err(source) => {
+                return Err(AnswerDbError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+        let answers = serde_json::from_str(&json).map_err(|source| AnswerDbError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(Self { answers })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswerDbError> {
+        let mut json = serde_json::to_string_pretty(&self.answers)
+            .expect("answers should always serialize to JSON");
+        json.push('\n');
+        fs::write(path, json).map_err(|source| AnswerDbError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.answers.get(&day)?.get(&part)
+    }
+
+    /// Record the answer for a day and part, returning the one it replaced.
+    pub fn insert(&mut self, day: u8, part: u8, answer: Answer) -> Option<Answer> {
+        self.answers.entry(day).or_default().insert(part, answer)
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum AnswerDbError {
+    #[error("could not read answers from {}", path.display())]
+    #[diagnostic(code(aoc_answer::db::read))]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("could not parse answers in {}", path.display())]
+    #[diagnostic(
+        code(aoc_answer::db::parse),
+        help("expected a JSON object mapping day to an object mapping part to its answer")
+    )]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("could not write answers to {}", path.display())]
+    #[diagnostic(code(aoc_answer::db::write))]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn db() -> AnswerDb {
+        let mut db = AnswerDb::new();
+        db.insert(1, 1, Answer::from(11));
+        db.insert(1, 2, Answer::from(31));
+        db.insert(17, 1, Answer::from("4,6,3,5,6,3,5,2,1,0"));
+        db
+    }
+
+    #[test]
+    fn test_check() {
+        let db = db();
+        assert_eq!(Verdict::Pass, db.check(1, 1, &Answer::from(11usize)));
+        assert_eq!(
+            Verdict::Fail {
+                expected: Answer::from(31)
+            },
+            db.check(1, 2, &Answer::from(30))
+        );
+        assert_eq!(
+            Verdict::Pass,
+            db.check(17, 1, &Answer::from("4,6,3,5,6,3,5,2,1,0"))
+        );
+        assert_eq!(Verdict::Unknown, db.check(2, 1, &Answer::from(2)));
+        assert_eq!(Verdict::Unknown, db.check(17, 2, &Answer::from(2)));
+    }
+
+    #[test]
+    fn test_save_and_load() -> miette::Result<()> {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.json", std::process::id()));
+        let db = db();
+        db.save(&path)?;
+        let loaded = AnswerDb::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(db, loaded?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing_is_empty() -> miette::Result<()> {
+        let db = AnswerDb::load(Path::new("/does/not/exist/answers.json"))?;
+        assert_eq!(None, db.get(1, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_numeric_keys() {
+        let db: BTreeMap<u8, BTreeMap<u8, Answer>> =
+            serde_json::from_str(r#"{"9": {"1": 1928, "2": "2858"}}"#).unwrap();
+        let db = AnswerDb { answers: db };
+        assert_eq!(Some(&Answer::from(1928)), db.get(9, 1));
+        assert_eq!(Verdict::Pass, db.check(9, 2, &Answer::from(2858)));
+    }
+}
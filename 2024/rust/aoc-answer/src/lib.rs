@@ -0,0 +1,229 @@
+use std::{borrow::Cow, fmt};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+pub mod db;
+
+/// The answer a solution produced, keeping track of what kind of value it is.
+///
+/// Answers compare equal when they render the same, so `Answer::Int(36)` equals `"36"` and an
+/// answer read back from a file equals the one that was written.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i64),
+    /// An integer that does not fit in an `i64`.
+    BigInt(i128),
+    Str(String),
+    /// Multi-line output, like the picture day 14 part 2 draws.
+    Art(String),
+}
+
+impl Answer {
+    pub fn art(art: impl Into<String>) -> Self {
+        Answer::Art(art.into())
+    }
+
+    /// The answer as it is displayed, without allocating for text answers.
+    fn text(&self) -> Cow<'_, str> {
+        match self {
+            Answer::Str(s) | Answer::Art(s) => Cow::Borrowed(s),
+            Answer::Int(_) | Answer::BigInt(_) => Cow::Owned(self.to_string()),
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Art(_) => true,
+            Answer::Str(s) => s.contains('\n'),
+            Answer::Int(_) | Answer::BigInt(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Str(s) | Answer::Art(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        i64::try_from(n).map_or(Answer::BigInt(n), Answer::Int)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::from(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            _ => self.text() == other.text(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.text() == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        *self == Answer::Int(*other)
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Answer> for String {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Answer> for i64 {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+/// Integers are written as numbers and everything else as strings. `BigInt`s are written as
+/// strings too, since most formats cannot hold 128-bit numbers.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => serializer.serialize_i64(*n),
+            Answer::BigInt(n) => serializer.collect_str(n),
+            Answer::Str(s) | Answer::Art(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+struct AnswerVisitor;
+
+impl de::Visitor<'_> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an integer or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+        Ok(Answer::Int(n))
+    }
+
+    fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+        Ok(Answer::from(n))
+    }
+
+    fn visit_i128<E: de::Error>(self, n: i128) -> Result<Answer, E> {
+        Ok(Answer::from(n))
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+        Ok(if s.contains('\n') {
+            Answer::art(s)
+        } else {
+            Answer::from(s)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Answer::from(36), "36")]
+    #[case(Answer::from(-2i64), "-2")]
+    #[case(Answer::from(u64::MAX), "18446744073709551615")]
+    #[case(Answer::from("26,63"), "26,63")]
+    #[case(Answer::art("#.\n.#"), "#.\n.#")]
+    fn test_display(#[case] answer: Answer, #[case] expected: &str) {
+        assert_eq!(expected, answer.to_string());
+    }
+
+    #[test]
+    fn test_big_ints_stay_exact() {
+        assert!(matches!(Answer::from(u64::MAX), Answer::BigInt(_)));
+        assert!(matches!(Answer::from(i64::MAX as u64), Answer::Int(_)));
+    }
+
+    #[test]
+    fn test_equality() {
+        assert_eq!("36", Answer::from(36usize));
+        assert_eq!(Answer::from(36u32), "36".to_string());
+        assert_eq!(36, Answer::from(36u8));
+        assert_eq!(Answer::from("36"), Answer::from(36));
+        assert_ne!(Answer::from(36), Answer::from(37));
+        assert_eq!(Answer::from("36"), 36);
+        assert_ne!(Answer::from("36 "), 36);
+    }
+
+    #[rstest]
+    #[case(Answer::from(4_000_000_000u64), "4000000000")]
+    #[case(Answer::from(u64::MAX), "\"18446744073709551615\"")]
+    #[case(Answer::from("co,de,ka,ta"), "\"co,de,ka,ta\"")]
+    #[case(Answer::art("#.\n.#"), "\"#.\\n.#\"")]
+    fn test_serde_round_trip(#[case] answer: Answer, #[case] json: &str) {
+        assert_eq!(json, serde_json::to_string(&answer).unwrap());
+        let back: Answer = serde_json::from_str(json).unwrap();
+        assert_eq!(answer, back);
+        assert_eq!(answer.is_multiline(), back.is_multiline());
+    }
+}
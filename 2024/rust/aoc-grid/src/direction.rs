@@ -0,0 +1,212 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use glam::IVec2;
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// A compass direction on a grid where north is up (`-y`) and east is right (`+x`).
+///
+/// Variants are declared clockwise from north, so the derived `Ord` and the [`Direction::ALL`]
+/// and [`Direction::CARDINAL`] arrays all share that order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+use Direction::*;
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [North, East, South, West];
+
+    /// The four diagonal directions, clockwise from north-east.
+    pub const DIAGONAL: [Direction; 4] = [NorthEast, SouthEast, SouthWest, NorthWest];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
+    ];
+
+    /// Rotate clockwise by `steps` eighths of a turn.
+    fn rotate(self, steps: usize) -> Self {
+        Self::ALL[(self as usize + steps) % 8]
+    }
+
+    /// Rotate 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Rotate 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn turn_around(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        matches!(self, North | East | South | West)
+    }
+
+    /// The unit step for this direction.
+    pub const fn to_ivec2(self) -> IVec2 {
+        match self {
+            North => IVec2::NEG_Y,
+            NorthEast => IVec2::new(1, -1),
+            East => IVec2::X,
+            SouthEast => IVec2::ONE,
+            South => IVec2::Y,
+            SouthWest => IVec2::new(-1, 1),
+            West => IVec2::NEG_X,
+            NorthWest => IVec2::NEG_ONE,
+        }
+    }
+
+    /// The direction whose unit step is `step`, if there is one.
+    pub fn from_ivec2(step: IVec2) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.to_ivec2() == step)
+    }
+
+    /// The `^>v<` arrow for a cardinal direction.
+    pub fn to_arrow(self) -> Option<char> {
+        match self {
+            North => Some('^'),
+            East => Some('>'),
+            South => Some('v'),
+            West => Some('<'),
+            _ => None,
+        }
+    }
+}
+
+impl From<Direction> for IVec2 {
+    fn from(dir: Direction) -> Self {
+        dir.to_ivec2()
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = DirectionError;
+
+    /// Parse a cardinal direction from an arrow (`^>v<`) or a compass letter (`NESW`).
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' => Ok(North),
+            '>' | 'E' => Ok(East),
+            'v' | 'S' => Ok(South),
+            '<' | 'W' => Ok(West),
+            _ => Err(DirectionError(c)),
+        }
+    }
+}
+
+impl Add<Direction> for IVec2 {
+    type Output = IVec2;
+
+    fn add(self, dir: Direction) -> IVec2 {
+        self + dir.to_ivec2()
+    }
+}
+
+impl AddAssign<Direction> for IVec2 {
+    fn add_assign(&mut self, dir: Direction) {
+        *self += dir.to_ivec2();
+    }
+}
+
+impl Sub<Direction> for IVec2 {
+    type Output = IVec2;
+
+    fn sub(self, dir: Direction) -> IVec2 {
+        self - dir.to_ivec2()
+    }
+}
+
+impl SubAssign<Direction> for IVec2 {
+    fn sub_assign(&mut self, dir: Direction) {
+        *self -= dir.to_ivec2();
+    }
+}
+
+#[derive(Debug, Error, Diagnostic, PartialEq, Eq)]
+#[error("{0:?} is not a direction")]
+#[diagnostic(code(aoc_grid::direction), help("expected one of `^>v<` or `NESW`"))]
+pub struct DirectionError(pub char);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(North, East, West, South)]
+    #[case(East, South, North, West)]
+    #[case(South, West, East, North)]
+    #[case(West, North, South, East)]
+    #[case(NorthEast, SouthEast, NorthWest, SouthWest)]
+    fn test_turns(
+        #[case] dir: Direction,
+        #[case] right: Direction,
+        #[case] left: Direction,
+        #[case] around: Direction,
+    ) {
+        assert_eq!(right, dir.turn_right());
+        assert_eq!(left, dir.turn_left());
+        assert_eq!(around, dir.turn_around());
+        assert_eq!(-dir.to_ivec2(), around.to_ivec2());
+    }
+
+    #[rstest]
+    #[case('^', North)]
+    #[case('N', North)]
+    #[case('>', East)]
+    #[case('E', East)]
+    #[case('v', South)]
+    #[case('S', South)]
+    #[case('<', West)]
+    #[case('W', West)]
+    fn test_parse(#[case] c: char, #[case] expected: Direction) {
+        assert_eq!(Ok(expected), Direction::try_from(c));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(DirectionError('x')), Direction::try_from('x'));
+    }
+
+    #[test]
+    fn test_order_is_clockwise() {
+        let mut sorted = Direction::ALL;
+        sorted.sort();
+        assert_eq!(Direction::ALL, sorted);
+        assert!(Direction::ALL
+            .iter()
+            .zip(Direction::ALL.iter().skip(1))
+            .all(|(a, b)| a.rotate(1) == *b));
+        assert_eq!(
+            Direction::CARDINAL.to_vec(),
+            Direction::ALL
+                .into_iter()
+                .filter(|d| d.is_cardinal())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_ivec2_round_trip() {
+        for dir in Direction::ALL {
+            assert_eq!(Some(dir), Direction::from_ivec2(dir.into()));
+        }
+        assert_eq!(None, Direction::from_ivec2(IVec2::new(2, 0)));
+        assert_eq!(IVec2::new(3, 4), IVec2::new(3, 5) + North);
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use glam::IVec2;
+use miette::Diagnostic;
+use thiserror::Error;
+
+pub mod direction;
+
+pub use direction::Direction;
+
+/// A dense, rectangular grid addressed by `IVec2 { x: column, y: row }` with the origin top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: i32, height: i32, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            width >= 0 && height >= 0,
+            "grid size should not be negative"
+        );
+        Self {
+            width,
+            height,
+            cells: vec![fill; (width * height) as usize],
+        }
+    }
+
+    /// Parse one cell per character and one row per line, rejecting characters `f` maps to `None`.
+    pub fn parse_with(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let pos = IVec2::new(x as i32, y as i32);
+                cells.push(f(c).ok_or(GridError::Cell { c, pos })?);
+            }
+
+            let found = cells.len() - row_start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::Ragged {
+                        row: y,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width: width as i32,
+                height,
+                cells,
+            }),
+            _ => Err(GridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn size(&self) -> IVec2 {
+        IVec2::new(self.width, self.height)
+    }
+
+    pub fn in_bounds(&self, pos: IVec2) -> bool {
+        (0..self.width).contains(&pos.x) && (0..self.height).contains(&pos.y)
+    }
+
+    fn index_of(&self, pos: IVec2) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| (pos.y * self.width + pos.x) as usize)
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| IVec2::new(x, y)))
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The in-bounds orthogonal neighbours of `pos`, in [`Direction::CARDINAL`] order.
+    pub fn neighbours4(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |d| pos + d)
+            .filter(|p| self.in_bounds(*p))
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `pos`, in [`Direction::ALL`] order.
+    pub fn neighbours8(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |d| pos + d)
+            .filter(|p| self.in_bounds(*p))
+    }
+
+    /// The first position holding `value` in row-major order.
+    pub fn find(&self, value: &T) -> Option<IVec2>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Every position holding `value` in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = IVec2> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Self::parse_with(input, Some)
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: IVec2) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, pos: IVec2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the {width}x{height} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.cells.chunks(self.width.max(1) as usize).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error, Diagnostic, PartialEq, Eq)]
+pub enum GridError {
+    #[error("grid input is empty")]
+    #[diagnostic(code(aoc_grid::empty))]
+    Empty,
+
+    #[error("row {row} has {found} cells, expected {expected}")]
+    #[diagnostic(
+        code(aoc_grid::ragged),
+        help("every line of a grid should be the same length")
+    )]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("unexpected {c:?} at {pos}")]
+    #[diagnostic(code(aoc_grid::cell))]
+    Cell { c: char, pos: IVec2 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "..#
+#.S
+...
+";
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let grid = Grid::parse(INPUT)?;
+        assert_eq!(IVec2::new(3, 3), grid.size());
+        assert_eq!('#', grid[IVec2::new(2, 0)]);
+        assert_eq!(Some(&'S'), grid.get(IVec2::new(2, 1)));
+        assert_eq!(None, grid.get(IVec2::new(3, 1)));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("", GridError::Empty)]
+    #[case("12\n3", GridError::Ragged { row: 1, expected: 2, found: 1 })]
+    #[case("12\n3x", GridError::Cell { c: 'x', pos: IVec2::new(1, 1) })]
+    fn test_parse_errors(#[case] input: &str, #[case] expected: GridError) {
+        assert_eq!(Err(expected), Grid::parse_with(input, |c| c.to_digit(10)));
+    }
+
+    #[rstest]
+    #[case(IVec2::new(0, 0), vec![IVec2::new(1, 0), IVec2::new(0, 1)])]
+    #[case(IVec2::new(1, 1), vec![IVec2::new(1, 0), IVec2::new(2, 1), IVec2::new(1, 2), IVec2::new(0, 1)])]
+    #[case(IVec2::new(2, 2), vec![IVec2::new(2, 1), IVec2::new(1, 2)])]
+    fn test_neighbours4(#[case] pos: IVec2, #[case] expected: Vec<IVec2>) -> miette::Result<()> {
+        let grid = Grid::parse(INPUT)?;
+        assert_eq!(expected, grid.neighbours4(pos).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbours8() -> miette::Result<()> {
+        let grid = Grid::parse(INPUT)?;
+        assert_eq!(8, grid.neighbours8(IVec2::new(1, 1)).count());
+        assert_eq!(
+            vec![IVec2::new(1, 0), IVec2::new(1, 1), IVec2::new(0, 1)],
+            grid.neighbours8(IVec2::new(0, 0)).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_find() -> miette::Result<()> {
+        let grid = Grid::parse(INPUT)?;
+        assert_eq!(Some(IVec2::new(2, 1)), grid.find(&'S'));
+        assert_eq!(None, grid.find(&'E'));
+        assert_eq!(
+            vec![IVec2::new(2, 0), IVec2::new(0, 1)],
+            grid.find_all(&'#').collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_round_trips() -> miette::Result<()> {
+        let mut grid = Grid::parse(INPUT)?;
+        grid[IVec2::new(0, 2)] = 'E';
+        assert_eq!("..#\n#.S\nE..", grid.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = Grid::new(2, 1, 1u8).map(|v| v * 3);
+        assert_eq!("33", grid.to_string());
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{file_name, input_dir};
+
+/// Session cookie for adventofcode.com, read from the environment or the workspace `.env`.
+pub const SESSION_ENV: &str = "SESSION";
+
+/// Overrides where inputs are downloaded from, e.g. a local server in tests.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const YEAR: u16 = 2024;
+
+const USER_AGENT: &str = "github.com/jfear/advent-of-code input fetcher";
+
+/// Downloads puzzle inputs into the directory the day crates read them from.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    session: String,
+    base_url: String,
+    dir: PathBuf,
+}
+
+/// What [`Fetcher::fetch`] did for a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// The input was already on disk, so nothing was requested.
+    Cached(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Fetched::Downloaded(path) | Fetched::Cached(path) => path,
+        }
+    }
+}
+
+impl Fetcher {
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            session: session.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            dir: input_dir(),
+        }
+    }
+
+    /// Build a fetcher from `SESSION` (falling back to the workspace `.env`) and `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = match env::var(SESSION_ENV) {
+            Ok(session) => session,
+            Err(_) => session_from_dotenv(&dotenv_path())?,
+        };
+        let fetcher = Self::new(session.trim());
+        Ok(match env::var(BASE_URL_ENV) {
+            Ok(url) => fetcher.with_base_url(url),
+            Err(_) => fetcher,
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = dir.into();
+        self
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/input", self.base_url)
+    }
+
+    /// Download a day's input unless it already exists; an existing file is never overwritten.
+    pub fn fetch(&self, day: u8) -> Result<Fetched, FetchError> {
+        let path = self.dir.join(file_name(day));
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = self.url(day);
+        let body = match ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+        {
+            Ok(response) => response.into_string().map_err(|source| FetchError::Body {
+                url: url.clone(),
+                source,
+            })?,
+            Err(ureq::Error::Status(status, _)) => return Err(FetchError::Status { url, status }),
+            Err(e) => {
+                return Err(FetchError::Request {
+                    url,
+                    source: Box::new(e),
+                })
+            }
+        };
+
+        // Write next to the target and rename so an interrupted fetch never looks cached.
+        let write = |path: &Path| -> io::Result<()> {
+            fs::create_dir_all(&self.dir)?;
+            let partial = path.with_extension("txt.part");
+            fs::write(&partial, body)?;
+            fs::rename(&partial, path)
+        };
+        write(&path).map_err(|source| FetchError::Write {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// The `.env` next to the workspace `justfile`.
+fn dotenv_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("crate should live inside the workspace")
+        .join(".env")
+}
+
+fn session_from_dotenv(path: &Path) -> Result<String, FetchError> {
+    let Ok(vars) = dotenvy::from_path_iter(path) else {
+        return Err(FetchError::NoSession);
+    };
+    for var in vars {
+        let (key, value) = var.map_err(|source| FetchError::Dotenv {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        if key == SESSION_ENV {
+            return Ok(value);
+        }
+    }
+    Err(FetchError::NoSession)
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum FetchError {
+    #[error("no SESSION cookie to fetch puzzle inputs with")]
+    #[diagnostic(
+        code(aoc_input::fetch::no_session),
+        help("set SESSION to your adventofcode.com session cookie, or add `SESSION=...` to .env")
+    )]
+    NoSession,
+
+    #[error("could not parse {}", path.display())]
+    #[diagnostic(code(aoc_input::fetch::dotenv))]
+    Dotenv {
+        path: PathBuf,
+        #[source]
+        source: dotenvy::Error,
+    },
+
+    #[error("request to {url} failed")]
+    #[diagnostic(code(aoc_input::fetch::request))]
+    Request {
+        url: String,
+        #[source]
+        source: Box<ureq::Error>,
+    },
+
+    #[error("{url} responded with HTTP {status}")]
+    #[diagnostic(
+        code(aoc_input::fetch::status),
+        help("a 400 usually means the SESSION cookie expired, a 404 that the puzzle is not unlocked yet")
+    )]
+    Status { url: String, status: u16 },
+
+    #[error("could not read the response from {url}")]
+    #[diagnostic(code(aoc_input::fetch::body))]
+    Body {
+        url: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("could not write puzzle input {}", path.display())]
+    #[diagnostic(code(aoc_input::fetch::write))]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serve a single request with `status` and `body`, handing back the request head.
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind local server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept request");
+            let head = BufReader::new(&stream)
+                .lines()
+                .map_while(Result::ok)
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<_>>();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .expect("write response");
+            head
+        });
+        (url, handle)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_downloads() -> miette::Result<()> {
+        let (url, server) = serve_once("200 OK", "125 17\n");
+        let dir = temp_dir("download");
+        let fetched = Fetcher::new("abc123")
+            .with_base_url(url)
+            .with_dir(&dir)
+            .fetch(11)?;
+
+        let head = server.join().expect("server thread");
+        assert_eq!("GET /2024/day/11/input HTTP/1.1", head[0]);
+        assert!(head.iter().any(|h| h == "Cookie: session=abc123"));
+        assert_eq!(Fetched::Downloaded(dir.join("input_day11.txt")), fetched);
+        assert_eq!("125 17\n", fs::read_to_string(fetched.path()).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_refuses_existing() -> miette::Result<()> {
+        let dir = temp_dir("cached");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input_day05.txt"), "mine").unwrap();
+
+        // Nothing listens here, so any request would fail.
+        let fetched = Fetcher::new("abc123")
+            .with_base_url("http://127.0.0.1:1")
+            .with_dir(&dir)
+            .fetch(5)?;
+
+        assert_eq!(Fetched::Cached(dir.join("input_day05.txt")), fetched);
+        assert_eq!("mine", fs::read_to_string(fetched.path()).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_bad_status() {
+        let (url, server) = serve_once("400 Bad Request", "Puzzle inputs differ by user.");
+        let dir = temp_dir("status");
+        let result = Fetcher::new("expired")
+            .with_base_url(url)
+            .with_dir(&dir)
+            .fetch(1);
+        server.join().expect("server thread");
+
+        assert!(matches!(
+            result,
+            Err(FetchError::Status { status: 400, .. })
+        ));
+        assert!(!dir.join("input_day01.txt").exists());
+    }
+
+    #[test]
+    fn test_session_from_dotenv() -> miette::Result<()> {
+        let dir = temp_dir("dotenv");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(".env");
+        fs::write(&path, "OTHER=1\nSESSION=cookie\n").unwrap();
+        assert_eq!("cookie", session_from_dotenv(&path)?);
+        assert!(matches!(
+            session_from_dotenv(&dir.join("missing.env")),
+            Err(FetchError::NoSession)
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    env,
+    ffi::OsString,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+pub mod fetch;
+
+/// Path to the input file to use, or `-` to read it from stdin.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Directory holding the `input_dayNN.txt` files.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Interpret a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: impl Into<OsString>) -> Self {
+        let arg = arg.into();
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(arg.into())
+        }
+    }
+
+    /// Use `AOC_INPUT` when it is set, otherwise `input_dayNN.txt` in [`input_dir`].
+    pub fn for_day(day: u8) -> Self {
+        resolve(day, env::var_os(INPUT_ENV), env::var_os(INPUT_DIR_ENV))
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::File(path) if !path.exists() => Err(InputError::Missing { path: path.clone() }),
+            Source::File(path) => {
+                std::fs::read_to_string(path).map_err(|source| InputError::Read {
+                    path: path.clone(),
+                    source,
+                })
+            }
+            Source::Stdin => {
+                let mut buf = String::new();
+                io::stdin()
+                    .read_to_string(&mut buf)
+                    .map_err(InputError::Stdin)?;
+                Ok(buf)
+            }
+        }
+    }
+}
+
+fn resolve(day: u8, input: Option<OsString>, dir: Option<OsString>) -> Source {
+    match (input, dir) {
+        (Some(input), _) => Source::from_arg(input),
+        (None, Some(dir)) => Source::File(PathBuf::from(dir).join(file_name(day))),
+        (None, None) => Source::File(default_dir().join(file_name(day))),
+    }
+}
+
+/// The name AoC inputs are stored under, e.g. `input_day05.txt`.
+pub fn file_name(day: u8) -> String {
+    format!("input_day{day:02}.txt")
+}
+
+/// `AOC_INPUT_DIR` when it is set, otherwise the `2024` directory next to this workspace.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(default_dir)
+}
+
+fn default_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("workspace should live two levels below the year directory")
+        .to_path_buf()
+}
+
+/// Load a day's input from `AOC_INPUT` or the input directory.
+pub fn load(day: u8) -> Result<String, InputError> {
+    Source::for_day(day).read()
+}
+
+/// Load a day's input for a `partN` binary, where the first argument (a path or `-`) takes
+/// precedence over the environment.
+pub fn load_cli(day: u8) -> Result<String, InputError> {
+    match env::args_os().nth(1) {
+        Some(arg) => Source::from_arg(arg).read(),
+        None => load(day),
+    }
+}
+
+/// [`load_cli`] for crates that kept their input next to them before the input directory, reading
+/// `legacy` when no argument is given and the day's input is not in the input directory.
+pub fn load_cli_or(day: u8, legacy: impl AsRef<Path>) -> Result<String, InputError> {
+    match env::args_os().nth(1) {
+        Some(arg) => Source::from_arg(arg).read(),
+        None => or_legacy(load(day), legacy.as_ref()),
+    }
+}
+
+fn or_legacy(result: Result<String, InputError>, legacy: &Path) -> Result<String, InputError> {
+    match result {
+        Err(InputError::Missing { .. }) if legacy.exists() => {
+            Source::File(legacy.to_path_buf()).read()
+        }
+        result => result,
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum InputError {
+    #[error("puzzle input {} does not exist", path.display())]
+    #[diagnostic(
+        code(aoc_input::missing),
+        help(
+            "download it with `just get-input <day>`, pass the input file (or `-` for stdin) \
+             as an argument, set AOC_INPUT to the file, or set AOC_INPUT_DIR to the directory \
+             holding your input_dayNN.txt files"
+        )
+    )]
+    Missing { path: PathBuf },
+
+    #[error("could not read puzzle input {}", path.display())]
+    #[diagnostic(code(aoc_input::read))]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("could not read puzzle input from stdin")]
+    #[diagnostic(code(aoc_input::stdin))]
+    Stdin(#[source] io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(5, None, None, Source::File(default_dir().join("input_day05.txt")))]
+    #[case(
+        5,
+        None,
+        Some("/inputs"),
+        Source::File(PathBuf::from("/inputs/input_day05.txt"))
+    )]
+    #[case(
+        5,
+        Some("mine.txt"),
+        Some("/inputs"),
+        Source::File(PathBuf::from("mine.txt"))
+    )]
+    #[case(12, Some("-"), None, Source::Stdin)]
+    fn test_resolve(
+        #[case] day: u8,
+        #[case] input: Option<&str>,
+        #[case] dir: Option<&str>,
+        #[case] expected: Source,
+    ) {
+        assert_eq!(
+            expected,
+            resolve(day, input.map(Into::into), dir.map(Into::into))
+        );
+    }
+
+    #[test]
+    fn test_default_dir() {
+        assert!(default_dir().ends_with("2024"));
+    }
+
+    #[test]
+    fn test_read_file() -> miette::Result<()> {
+        let path = env::temp_dir().join(format!("aoc-input-test-{}.txt", std::process::id()));
+        std::fs::write(&path, "125 17").map_err(|e| miette::miette!("{e}"))?;
+        let result = Source::File(path.clone()).read();
+        std::fs::remove_file(&path).map_err(|e| miette::miette!("{e}"))?;
+        assert_eq!("125 17", result?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_or_legacy() -> miette::Result<()> {
+        let legacy = env::temp_dir().join(format!("aoc-input-legacy-{}.txt", std::process::id()));
+        let missing = || {
+            Err(InputError::Missing {
+                path: PathBuf::from("/does/not/exist/input_day01.txt"),
+            })
+        };
+        assert!(matches!(
+            or_legacy(missing(), &legacy),
+            Err(InputError::Missing { .. })
+        ));
+
+        std::fs::write(&legacy, "3   4").map_err(|e| miette::miette!("{e}"))?;
+        let result = or_legacy(missing(), &legacy);
+        let found = or_legacy(Ok("1   2".to_string()), &legacy);
+        std::fs::remove_file(&legacy).map_err(|e| miette::miette!("{e}"))?;
+        assert_eq!("3   4", result?);
+        assert_eq!("1   2", found?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let path = PathBuf::from("/does/not/exist/input_day01.txt");
+        assert!(matches!(
+            Source::File(path).read(),
+            Err(InputError::Missing { .. })
+        ));
+    }
+}
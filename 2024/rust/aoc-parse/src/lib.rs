@@ -0,0 +1,164 @@
+use std::fmt;
+
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+use winnow::{ascii::multispace0, combinator::terminated, error::ContextError, Located, Parser};
+
+pub mod scan;
+
+pub use scan::Scanner;
+
+/// Run `parser` over the whole of `input`, allowing trailing whitespace such as the final newline.
+///
+/// Anything else left over, or any error the parser reports, becomes a [`ParseError`] pointing at
+/// the offending byte.
+pub fn parse<'i, O>(
+    input: &'i str,
+    parser: impl Parser<&'i str, O, ContextError>,
+) -> Result<O, ParseError> {
+    terminated(parser, multispace0)
+        .parse(input)
+        .map_err(|e| ParseError::new(input, e.offset(), e.inner()))
+}
+
+/// Like [`parse`], but over a [`Located`] stream so the parser can ask where it is in the input.
+pub fn parse_located<'i, O>(
+    input: &'i str,
+    parser: impl Parser<Located<&'i str>, O, ContextError>,
+) -> Result<O, ParseError> {
+    terminated(parser, multispace0)
+        .parse(Located::new(input))
+        .map_err(|e| ParseError::new(input, e.offset(), e.inner()))
+}
+
+/// A puzzle input that could not be parsed, labelled at the line and column where parsing stopped.
+#[derive(Debug, Error, Diagnostic)]
+#[error("could not parse the puzzle input at line {line}, column {column}")]
+#[diagnostic(code(aoc_parse::parse))]
+pub struct ParseError {
+    #[source_code]
+    input: String,
+    #[label("{reason}")]
+    span: SourceSpan,
+    reason: String,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    /// An error at byte `offset` of `input`, where `reason` is the parser's explanation, if any.
+    pub fn new(input: &str, offset: usize, reason: impl fmt::Display) -> Self {
+        let offset = offset.min(input.len());
+        let len = input[offset..].chars().next().map_or(0, char::len_utf8);
+        let reason = match reason.to_string() {
+            reason if !reason.is_empty() => reason,
+            _ if offset == input.len() => "unexpected end of input".to_string(),
+            _ => "unexpected input".to_string(),
+        };
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Self {
+            input: input.to_string(),
+            span: (offset, len).into(),
+            reason,
+            line,
+            column,
+        }
+    }
+
+    /// Byte offset into the input where parsing stopped.
+    pub fn offset(&self) -> usize {
+        self.span.offset()
+    }
+
+    /// 1-based line of [`ParseError::offset`].
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based column, in characters, of [`ParseError::offset`].
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::ops::Range;
+    use winnow::{
+        ascii::{alpha1, dec_uint, line_ending},
+        combinator::{separated, separated_pair},
+        error::StrContext,
+        PResult,
+    };
+
+    fn pair(input: &mut &str) -> PResult<(u32, u32)> {
+        separated_pair(dec_uint, '|', dec_uint)
+            .context(StrContext::Label("page pair"))
+            .parse_next(input)
+    }
+
+    fn pairs(input: &mut &str) -> PResult<Vec<(u32, u32)>> {
+        separated(1.., pair, line_ending).parse_next(input)
+    }
+
+    #[rstest]
+    #[case("47|53\n97|13")]
+    #[case("47|53\n97|13\n")]
+    #[case("47|53\r\n97|13\r\n\r\n")]
+    fn test_parse_allows_trailing_whitespace(#[case] input: &str) -> miette::Result<()> {
+        assert_eq!(vec![(47, 53), (97, 13)], parse(input, pairs)?);
+        Ok(())
+    }
+
+    #[rstest]
+    // Repetition backtracks to the start of the item that failed.
+    #[case("47|53\n97,13\n", 6, 2, 1)]
+    #[case("47|53\n97|13\nxx", 12, 3, 1)]
+    #[case("47|", 3, 1, 4)]
+    fn test_parse_error_location(
+        #[case] input: &str,
+        #[case] offset: usize,
+        #[case] line: usize,
+        #[case] column: usize,
+    ) {
+        let err = parse(input, pairs).unwrap_err();
+        assert_eq!(
+            (offset, line, column),
+            (err.offset(), err.line(), err.column())
+        );
+    }
+
+    #[test]
+    fn test_parse_located() -> miette::Result<()> {
+        let words = separated(1.., alpha1.span(), ' ');
+        let spans: Vec<Range<usize>> = parse_located("ab cde\n", words)?;
+        assert_eq!(vec![0..2, 3..6], spans);
+
+        let err = parse_located("ab 1", separated(1.., alpha1, ' ').map(|_: Vec<_>| ()));
+        assert_eq!(3, err.unwrap_err().offset());
+        Ok(())
+    }
+
+    #[test]
+    fn test_label() {
+        let err = ParseError::new("ab\ncd", 4, "");
+        assert_eq!((2, 2), (err.line(), err.column()));
+        let label = err.labels().unwrap().next().unwrap();
+        assert_eq!(4, label.offset());
+        assert_eq!(1, label.len());
+        assert_eq!(Some("unexpected input"), label.label());
+
+        let err = ParseError::new("ab", 2, "");
+        assert_eq!(
+            Some("unexpected end of input"),
+            err.labels().unwrap().next().unwrap().label()
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::ops::Range;
+
+use glam::IVec2;
+use winnow::{
+    ascii::line_ending,
+    combinator::{repeat, separated},
+    token::none_of,
+    Located, PResult, Parser,
+};
+
+use crate::{parse_located, ParseError};
+
+type Span<'i> = Located<&'i str>;
+
+/// Scans a rectangular character grid for the cells that are not background, like the antennas
+/// on day 8's map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner {
+    background: Vec<char>,
+}
+
+/// The tokens a [`Scanner`] found and the size of the grid they were found in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scan {
+    pub size: IVec2,
+    /// Every non-background cell in row-major order.
+    pub tokens: Vec<(char, IVec2)>,
+}
+
+impl Scan {
+    pub fn in_bounds(&self, pos: IVec2) -> bool {
+        (0..self.size.x).contains(&pos.x) && (0..self.size.y).contains(&pos.y)
+    }
+}
+
+/// A cell and the byte range it was read from.
+type Cell = (char, Range<usize>);
+
+fn cell(input: &mut Span) -> PResult<Cell> {
+    none_of(['\r', '\n']).with_span().parse_next(input)
+}
+
+fn row(input: &mut Span) -> PResult<(Vec<Cell>, Range<usize>)> {
+    repeat(1.., cell).with_span().parse_next(input)
+}
+
+fn rows(input: &mut Span) -> PResult<Vec<(Vec<Cell>, Range<usize>)>> {
+    separated(1.., row, line_ending).parse_next(input)
+}
+
+impl Scanner {
+    /// A scanner that skips every character in `background`.
+    pub fn new(background: impl IntoIterator<Item = char>) -> Self {
+        Self {
+            background: background.into_iter().collect(),
+        }
+    }
+
+    pub fn scan(&self, input: &str) -> Result<Scan, ParseError> {
+        let rows = parse_located(input, rows)?;
+
+        let width = rows[0].0.len();
+        let mut tokens = vec![];
+        for (y, (cells, span)) in rows.iter().enumerate() {
+            if cells.len() != width {
+                // Point at the first extra cell, or just past the end of a short row.
+                let offset = cells.get(width).map_or(span.end, |(_, at)| at.start);
+                return Err(ParseError::new(
+                    input,
+                    offset,
+                    format!("row {} has {} cells, expected {width}", y + 1, cells.len()),
+                ));
+            }
+
+            tokens.extend(
+                cells
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (c, _))| !self.background.contains(c))
+                    .map(|(x, (c, _))| (*c, IVec2::new(x as i32, y as i32))),
+            );
+        }
+
+        Ok(Scan {
+            size: IVec2::new(width as i32, rows.len() as i32),
+            tokens,
+        })
+    }
+}
+
+/// Treats `.` as background.
+impl Default for Scanner {
+    fn default() -> Self {
+        Self::new(['.'])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn test_scan() -> miette::Result<()> {
+        let scan = Scanner::default().scan(INPUT)?;
+        assert_eq!(IVec2::new(12, 12), scan.size);
+        assert_eq!(7, scan.tokens.len());
+        assert_eq!(('0', IVec2::new(8, 1)), scan.tokens[0]);
+        assert_eq!(('A', IVec2::new(9, 9)), scan.tokens[6]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_background() -> miette::Result<()> {
+        let scan = Scanner::new(['.', '#']).scan("#.S\n.E#\r\n")?;
+        assert_eq!(
+            vec![('S', IVec2::new(2, 0)), ('E', IVec2::new(1, 1))],
+            scan.tokens
+        );
+        assert!(scan.in_bounds(IVec2::new(2, 1)));
+        assert!(!scan.in_bounds(IVec2::new(3, 1)));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("..\n...\n", 5, 2, 3)]
+    #[case("...\n..\n", 6, 2, 3)]
+    #[case("", 0, 1, 1)]
+    fn test_ragged(
+        #[case] input: &str,
+        #[case] offset: usize,
+        #[case] line: usize,
+        #[case] column: usize,
+    ) {
+        let err = Scanner::default().scan(input).unwrap_err();
+        assert_eq!(
+            (offset, line, column),
+            (err.offset(), err.line(), err.column())
+        );
+    }
+}
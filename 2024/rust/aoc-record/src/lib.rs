@@ -0,0 +1,310 @@
+//! Records a grid frame by frame, e.g. after every move of a simulation, as an animated GIF or
+//! an [asciinema](https://asciinema.org) cast.
+//!
+//! Frames are written as they are recorded, so long simulations never hold more than one frame.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_grid::Grid;
+use gif::{Encoder, Repeat};
+use glam::IVec2;
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// What a recording is written as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// An animated GIF with one coloured square per cell.
+    Gif,
+    /// An asciinema v2 cast replaying the frames as text.
+    Cast,
+}
+
+impl Format {
+    /// Pick the format from a `.gif` or `.cast` extension.
+    pub fn from_path(path: &Path) -> Result<Self, RecordError> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("gif") => Ok(Format::Gif),
+            Some("cast") => Ok(Format::Cast),
+            _ => Err(RecordError::Format {
+                path: path.to_path_buf(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Pixels per cell along each side, for GIFs.
+    pub scale: u16,
+    /// How long every frame is shown.
+    pub delay: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            scale: 4,
+            delay: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Background, walls, robots, boxes, other marks and anything else, as RGB.
+const PALETTE: [[u8; 3]; 6] = [
+    [15, 15, 35],
+    [110, 110, 120],
+    [230, 60, 60],
+    [200, 150, 60],
+    [70, 200, 100],
+    [240, 240, 240],
+];
+
+/// The palette index a cell is drawn with.
+fn colour(c: char) -> u8 {
+    match c {
+        '.' | ' ' => 0,
+        '#' => 1,
+        '@' => 2,
+        'O' | '[' | ']' => 3,
+        '0'..='9' | '*' | '+' => 4,
+        _ => 5,
+    }
+}
+
+enum Sink<W: Write> {
+    Gif(Encoder<W>),
+    Cast(W),
+}
+
+/// Writes frames of a fixed size to `W` as they are recorded.
+pub struct Recorder<W: Write> {
+    sink: Sink<W>,
+    size: IVec2,
+    options: Options,
+    frames: usize,
+}
+
+impl Recorder<BufWriter<File>> {
+    /// Record to a new file, in the format its extension asks for.
+    pub fn create(path: &Path, size: IVec2, options: Options) -> Result<Self, RecordError> {
+        let format = Format::from_path(path)?;
+        let file = File::create(path).map_err(|source| RecordError::Create {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::new(BufWriter::new(file), format, size, options)
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(
+        mut writer: W,
+        format: Format,
+        size: IVec2,
+        options: Options,
+    ) -> Result<Self, RecordError> {
+        let sink = match format {
+            Format::Gif => {
+                let too_large = || RecordError::TooLarge {
+                    size,
+                    scale: options.scale,
+                };
+                let width =
+                    u16::try_from(size.x * options.scale as i32).map_err(|_| too_large())?;
+                let height =
+                    u16::try_from(size.y * options.scale as i32).map_err(|_| too_large())?;
+                let mut encoder = Encoder::new(writer, width, height, PALETTE.as_flattened())?;
+                encoder.set_repeat(Repeat::Infinite)?;
+                Sink::Gif(encoder)
+            }
+            Format::Cast => {
+                writeln!(
+                    writer,
+                    r#"{{"version": 2, "width": {}, "height": {}}}"#,
+                    size.x, size.y
+                )?;
+                Sink::Cast(writer)
+            }
+        };
+        Ok(Self {
+            sink,
+            size,
+            options,
+            frames: 0,
+        })
+    }
+
+    /// Append `frame`, which must be the size the recording was started with.
+    pub fn record(&mut self, frame: &Grid<char>) -> Result<(), RecordError> {
+        if frame.size() != self.size {
+            return Err(RecordError::FrameSize {
+                expected: self.size,
+                found: frame.size(),
+            });
+        }
+
+        match &mut self.sink {
+            Sink::Gif(encoder) => {
+                let scale = self.options.scale as usize;
+                let mut pixels = Vec::with_capacity(frame.iter().count() * scale * scale);
+                for y in 0..self.size.y {
+                    let row = (0..self.size.x)
+                        .flat_map(|x| {
+                            let c = colour(frame[IVec2::new(x, y)]);
+                            std::iter::repeat_n(c, scale)
+                        })
+                        .collect::<Vec<_>>();
+                    for _ in 0..scale {
+                        pixels.extend_from_slice(&row);
+                    }
+                }
+                let (width, height) = (
+                    (self.size.x as usize * scale) as u16,
+                    (self.size.y as usize * scale) as u16,
+                );
+                let mut gif_frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+                gif_frame.delay = (self.options.delay.as_millis() / 10) as u16;
+                encoder.write_frame(&gif_frame)?;
+            }
+            Sink::Cast(writer) => {
+                // Clear the screen once, then redraw from the top left for every frame.
+                let clear = if self.frames == 0 { "\x1b[2J" } else { "" };
+                let text = format!("{clear}\x1b[H{}", frame.to_string().replace('\n', "\r\n"));
+                let time = self.options.delay.as_secs_f64() * self.frames as f64;
+                writeln!(
+                    writer,
+                    "[{time:.3}, \"o\", {}]",
+                    serde_json::Value::String(text)
+                )?;
+            }
+        }
+        self.frames += 1;
+        Ok(())
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Finish the recording and hand back the writer.
+    pub fn finish(self) -> Result<W, RecordError> {
+        let mut writer = match self.sink {
+            Sink::Gif(encoder) => encoder.into_inner()?,
+            Sink::Cast(writer) => writer,
+        };
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum RecordError {
+    #[error("cannot tell what to record {} as", path.display())]
+    #[diagnostic(
+        code(aoc_record::format),
+        help("use a `.gif` or an asciinema `.cast` file")
+    )]
+    Format { path: PathBuf },
+
+    #[error("could not create {}", path.display())]
+    #[diagnostic(code(aoc_record::create))]
+    Create {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("a {size} grid at {scale} pixels per cell is too large for a GIF")]
+    #[diagnostic(code(aoc_record::too_large), help("use a smaller scale"))]
+    TooLarge { size: IVec2, scale: u16 },
+
+    #[error("frame is {found}, but the recording is {expected}")]
+    #[diagnostic(code(aoc_record::frame_size))]
+    FrameSize { expected: IVec2, found: IVec2 },
+
+    #[error("could not write the recording")]
+    #[diagnostic(code(aoc_record::write))]
+    Write(#[from] io::Error),
+
+    #[error("could not encode the GIF")]
+    #[diagnostic(code(aoc_record::gif))]
+    Gif(#[from] gif::EncodingError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn frames() -> miette::Result<Vec<Grid<char>>> {
+        Ok(vec![Grid::parse("#@.\n#O.")?, Grid::parse("#.@\n#O.")?])
+    }
+
+    #[rstest]
+    #[case("robots.gif", Some(Format::Gif))]
+    #[case("out/warehouse.cast", Some(Format::Cast))]
+    #[case("robots.png", None)]
+    #[case("robots", None)]
+    fn test_format_from_path(#[case] path: &str, #[case] expected: Option<Format>) {
+        assert_eq!(expected, Format::from_path(Path::new(path)).ok());
+    }
+
+    #[test]
+    fn test_gif() -> miette::Result<()> {
+        let options = Options {
+            scale: 2,
+            delay: Duration::from_millis(50),
+        };
+        let mut recorder = Recorder::new(vec![], Format::Gif, IVec2::new(3, 2), options)?;
+        for frame in frames()? {
+            recorder.record(&frame)?;
+        }
+        assert_eq!(2, recorder.frames());
+        let gif = recorder.finish()?;
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(gif.as_slice()).unwrap();
+        assert_eq!((6, 4), (decoder.width(), decoder.height()));
+        let first = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!(5, first.delay);
+        // The top row is a wall, the robot and the floor, each two pixels wide.
+        assert_eq!(&[1, 1, 2, 2, 0, 0], &first.buffer[..6]);
+        assert!(decoder.read_next_frame().unwrap().is_some());
+        assert!(decoder.read_next_frame().unwrap().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast() -> miette::Result<()> {
+        let mut recorder =
+            Recorder::new(vec![], Format::Cast, IVec2::new(3, 2), Options::default())?;
+        for frame in frames()? {
+            recorder.record(&frame)?;
+        }
+        let cast = String::from_utf8(recorder.finish()?).unwrap();
+        let lines = cast.lines().collect::<Vec<_>>();
+        assert_eq!(r#"{"version": 2, "width": 3, "height": 2}"#, lines[0]);
+        assert_eq!(r#"[0.000, "o", "\u001b[2J\u001b[H#@.\r\n#O."]"#, lines[1]);
+        assert_eq!(r#"[0.100, "o", "\u001b[H#.@\r\n#O."]"#, lines[2]);
+        assert_eq!(3, lines.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_size() -> miette::Result<()> {
+        let mut recorder =
+            Recorder::new(vec![], Format::Cast, IVec2::new(2, 2), Options::default())?;
+        assert!(matches!(
+            recorder.record(&Grid::parse("#@.\n#O.")?),
+            Err(RecordError::FrameSize { .. })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,585 @@
+//! Graph searches over any hashable state, such as a grid position or `(IVec2, Direction)`.
+//!
+//! Successor functions return the states reachable from a state, paired with the cost of the move
+//! for the weighted searches. Costs must not be negative.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// What the weighted searches need from a cost; `Default` is taken to be zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Interns states so the searches can keep them in flat vectors and in the heap.
+struct Nodes<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Nodes<N> {
+    fn new(start: &N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            index: HashMap::from([(start.clone(), 0)]),
+        }
+    }
+
+    /// The index of `node`, and whether it was seen for the first time.
+    fn intern(&mut self, node: N) -> (usize, bool) {
+        match self.index.entry(node) {
+            Entry::Occupied(e) => (*e.get(), false),
+            Entry::Vacant(e) => {
+                let i = self.nodes.len();
+                self.nodes.push(e.key().clone());
+                e.insert(i);
+                (i, true)
+            }
+        }
+    }
+
+    /// Walk `parents` back from `end` to the start.
+    fn path(&self, parents: &[usize], mut end: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[end].clone()];
+        while end != 0 {
+            end = parents[end];
+            path.push(self.nodes[end].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// A shortest path from `start` to the first state satisfying `success`, counting moves.
+pub fn bfs<N, FN, IN>(
+    start: &N,
+    mut successors: FN,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut nodes = Nodes::new(start);
+    let mut parents = vec![0];
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        if success(&nodes.nodes[i]) {
+            return Some(nodes.path(&parents, i));
+        }
+        for next in successors(&nodes.nodes[i]) {
+            let (j, new) = nodes.intern(next);
+            if new {
+                parents.push(i);
+                queue.push_back(j);
+            }
+        }
+    }
+    None
+}
+
+/// The number of moves from `start` to every state reachable from it.
+pub fn bfs_distances<N, FN, IN>(start: &N, mut successors: FN) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start.clone()]);
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node] + 1;
+        for next in successors(&node) {
+            if let Entry::Vacant(e) = distances.entry(next.clone()) {
+                e.insert(distance);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// A cheapest path from `start` to the first state satisfying `success`, and its cost.
+pub fn dijkstra<N, C, FN, IN>(
+    start: &N,
+    successors: FN,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that must never overestimate the remaining cost.
+pub fn astar<N, C, FN, IN>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new(start);
+    let mut costs = vec![C::default()];
+    let mut parents = vec![0];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(start), C::default(), 0))]);
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+        if success(&nodes.nodes[i]) {
+            return Some((nodes.path(&parents, i), cost));
+        }
+        for (next, step) in successors(&nodes.nodes[i]) {
+            let next_cost = cost + step;
+            let (j, new) = nodes.intern(next);
+            if new {
+                costs.push(next_cost);
+                parents.push(i);
+            } else if next_cost < costs[j] {
+                costs[j] = next_cost;
+                parents[j] = i;
+            } else {
+                continue;
+            }
+            let estimate = next_cost + heuristic(&nodes.nodes[j]);
+            heap.push(Reverse((estimate, next_cost, j)));
+        }
+    }
+    None
+}
+
+/// The cost of the cheapest path from any of `starts` to every state reachable from them.
+///
+/// Searching backwards from every goal at once gives the cost to the nearest goal, which together
+/// with a forward search from the start tells which states lie on some cheapest path.
+pub fn dijkstra_distances<N, C, FN, IN>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: FN,
+) -> HashMap<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes {
+        nodes: vec![],
+        index: HashMap::new(),
+    };
+    let mut costs = vec![];
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let (i, new) = nodes.intern(start);
+        if new {
+            costs.push(C::default());
+            heap.push(Reverse((C::default(), i)));
+        }
+    }
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+        for (next, step) in successors(&nodes.nodes[i]) {
+            let next_cost = cost + step;
+            let (j, new) = nodes.intern(next);
+            if new {
+                costs.push(next_cost);
+            } else if next_cost < costs[j] {
+                costs[j] = next_cost;
+            } else {
+                continue;
+            }
+            heap.push(Reverse((next_cost, j)));
+        }
+    }
+    nodes.nodes.into_iter().zip(costs).collect()
+}
+
+/// Every cheapest path from `start` to the states satisfying `success`, as a predecessor DAG.
+///
+/// All targets reachable at the lowest cost are kept, so on day 10 every height 9 a trail can end
+/// on is a target.
+///
+/// Zero-cost moves are allowed: a state only records predecessors settled before it, so the
+/// predecessors always form a DAG even when a zero-cost cycle ties.
+pub fn dijkstra_all<N, C, FN, IN>(
+    start: &N,
+    mut successors: FN,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<ShortestPaths<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new(start);
+    let mut costs = vec![C::default()];
+    let mut predecessors: Vec<Vec<usize>> = vec![vec![]];
+    let mut settled = vec![false];
+    let mut order = vec![];
+    let mut targets = vec![];
+    let mut best = None;
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        if cost > costs[i] || settled[i] {
+            continue;
+        }
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        settled[i] = true;
+        order.push(i);
+        if success(&nodes.nodes[i]) {
+            best = Some(cost);
+            targets.push(i);
+        }
+        for (next, step) in successors(&nodes.nodes[i]) {
+            let next_cost = cost + step;
+            let (j, new) = nodes.intern(next);
+            if new {
+                costs.push(next_cost);
+                predecessors.push(vec![i]);
+                settled.push(false);
+            } else if settled[j] {
+                // Only a zero-cost move can tie with a settled state; linking it could close a cycle.
+                continue;
+            } else if next_cost < costs[j] {
+                costs[j] = next_cost;
+                predecessors[j] = vec![i];
+            } else {
+                if next_cost == costs[j] && !predecessors[j].contains(&i) {
+                    predecessors[j].push(i);
+                }
+                continue;
+            }
+            heap.push(Reverse((next_cost, j)));
+        }
+    }
+
+    let cost = best?;
+    Some(ShortestPaths {
+        cost,
+        targets: targets.iter().map(|&t| nodes.nodes[t].clone()).collect(),
+        order: order.iter().map(|&i| nodes.nodes[i].clone()).collect(),
+        predecessors: nodes
+            .nodes
+            .iter()
+            .zip(predecessors)
+            .filter(|(_, preds)| !preds.is_empty())
+            .map(|(node, preds)| {
+                let preds = preds.iter().map(|&p| nodes.nodes[p].clone()).collect();
+                (node.clone(), preds)
+            })
+            .collect(),
+        start: start.clone(),
+    })
+}
+
+/// [`dijkstra_all`] where every move costs one.
+pub fn bfs_all<N, FN, IN>(
+    start: &N,
+    mut successors: FN,
+    success: impl FnMut(&N) -> bool,
+) -> Option<ShortestPaths<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    dijkstra_all(
+        start,
+        |node| successors(node).into_iter().map(|next| (next, 1)),
+        success,
+    )
+}
+
+/// The result of [`dijkstra_all`]: the optimal cost and, for every state on a cheapest path, the
+/// states it can be reached from at that cost.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    start: N,
+    cost: C,
+    targets: Vec<N>,
+    /// Settled states in the order they were settled, so predecessors come before successors.
+    order: Vec<N>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> ShortestPaths<N, C> {
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    /// The states satisfying `success` that were reached at [`ShortestPaths::cost`].
+    pub fn targets(&self) -> &[N] {
+        &self.targets
+    }
+
+    /// The states `node` is reached from on a cheapest path to it.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every state on at least one cheapest path to a target.
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        let mut stack = self.targets.clone();
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// How many distinct cheapest paths lead to `target`.
+    pub fn count_to(&self, target: &N) -> u64 {
+        self.counts().get(target).copied().unwrap_or(0)
+    }
+
+    /// How many distinct cheapest paths there are, over all targets.
+    pub fn count(&self) -> u64 {
+        let counts = self.counts();
+        self.targets.iter().map(|t| counts[t]).sum()
+    }
+
+    /// The number of cheapest paths to every settled state, filled in settle order so each
+    /// state's predecessors are counted before it.
+    fn counts(&self) -> HashMap<N, u64> {
+        let mut counts = HashMap::from([(self.start.clone(), 1)]);
+        for node in &self.order {
+            if *node == self.start {
+                continue;
+            }
+            let count = self
+                .predecessors(node)
+                .iter()
+                .map(|p| counts.get(p).copied().unwrap_or(0))
+                .sum();
+            counts.insert(node.clone(), count);
+        }
+        counts
+    }
+
+    /// Every cheapest path from the start to each target.
+    ///
+    /// There can be exponentially many; use [`ShortestPaths::count`] or
+    /// [`ShortestPaths::nodes`] when the paths themselves are not needed.
+    pub fn paths(&self) -> Vec<Vec<N>> {
+        let mut paths = vec![];
+        for target in &self.targets {
+            let mut stack = vec![vec![target.clone()]];
+            while let Some(path) = stack.pop() {
+                let last = path.last().expect("paths are never empty");
+                if *last == self.start {
+                    paths.push(path.iter().rev().cloned().collect());
+                    continue;
+                }
+                for pred in self.predecessors(last) {
+                    let mut longer = path.clone();
+                    longer.push(pred.clone());
+                    stack.push(longer);
+                }
+            }
+        }
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::{Direction, Grid};
+    use glam::IVec2;
+    use rstest::rstest;
+
+    const MAZE: &str = "#######
+#S...##
+#.##.##
+#....E#
+#######";
+
+    fn open(maze: &Grid<char>) -> impl Fn(&IVec2) -> Vec<IVec2> + '_ {
+        |&pos| maze.neighbours4(pos).filter(|&p| maze[p] != '#').collect()
+    }
+
+    #[test]
+    fn test_bfs() -> miette::Result<()> {
+        let maze = Grid::parse(MAZE)?;
+        let (start, end) = (IVec2::new(1, 1), IVec2::new(5, 3));
+        let path = bfs(&start, open(&maze), |&p| p == end).expect("maze is solvable");
+        assert_eq!(7, path.len());
+        assert_eq!((start, end), (path[0], path[6]));
+        assert_eq!(None, bfs(&start, open(&maze), |&p| p == IVec2::ZERO));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bfs_distances() -> miette::Result<()> {
+        let maze = Grid::parse(MAZE)?;
+        let distances = bfs_distances(&IVec2::new(5, 3), open(&maze));
+        assert_eq!(11, distances.len());
+        assert_eq!(Some(&6), distances.get(&IVec2::new(1, 1)));
+        assert_eq!(Some(&2), distances.get(&IVec2::new(4, 2)));
+        assert_eq!(None, distances.get(&IVec2::ZERO));
+        Ok(())
+    }
+
+    type Reindeer = (IVec2, Direction);
+
+    /// Moving costs 1 and turning costs 1000, like the reindeer maze on day 16.
+    fn reindeer(maze: &Grid<char>) -> impl Fn(&Reindeer) -> Vec<(Reindeer, u32)> + '_ {
+        |&(pos, dir)| {
+            let mut next = vec![
+                ((pos, dir.turn_left()), 1000),
+                ((pos, dir.turn_right()), 1000),
+            ];
+            if maze[pos + dir] != '#' {
+                next.push(((pos + dir, dir), 1));
+            }
+            next
+        }
+    }
+
+    #[rstest]
+    #[case(Direction::East, 2006)]
+    #[case(Direction::South, 1006)]
+    #[case(Direction::West, 2006)]
+    fn test_dijkstra(#[case] facing: Direction, #[case] expected: u32) -> miette::Result<()> {
+        let maze = Grid::parse(MAZE)?;
+        let end = IVec2::new(5, 3);
+        let (path, cost) = dijkstra(&(IVec2::new(1, 1), facing), reindeer(&maze), |&(p, _)| {
+            p == end
+        })
+        .expect("maze is solvable");
+        assert_eq!(expected, cost);
+        assert_eq!(end, path.last().unwrap().0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() -> miette::Result<()> {
+        let maze = Grid::parse(MAZE)?;
+        let (start, end) = (IVec2::new(1, 1), IVec2::new(5, 3));
+        let successors = |p: &IVec2| open(&maze)(p).into_iter().map(|n| (n, 1));
+        let manhattan = |p: &IVec2| (end - *p).abs().element_sum();
+        let (path, cost) = astar(&start, successors, manhattan, |&p| p == end).expect("solvable");
+        assert_eq!(6, cost);
+        assert_eq!(7, path.len());
+        assert_eq!(
+            Some((path, cost)),
+            dijkstra(&start, successors, |&p| p == end)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dijkstra_distances() -> miette::Result<()> {
+        let maze = Grid::parse(MAZE)?;
+        let start = (IVec2::new(1, 1), Direction::East);
+        let costs = dijkstra_distances([start], reindeer(&maze));
+        assert_eq!(Some(&0), costs.get(&start));
+        assert_eq!(
+            Some(&1000),
+            costs.get(&(IVec2::new(1, 1), Direction::South))
+        );
+        assert_eq!(Some(&2006), costs.get(&(IVec2::new(5, 3), Direction::East)));
+
+        // Starting from every direction at once takes the cheapest of them.
+        let costs = dijkstra_distances(
+            Direction::CARDINAL.map(|d| (IVec2::new(1, 1), d)),
+            reindeer(&maze),
+        );
+        assert_eq!(Some(&1006), costs.get(&(IVec2::new(5, 3), Direction::East)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dijkstra_all() -> miette::Result<()> {
+        let maze = Grid::parse(MAZE)?;
+        let end = IVec2::new(5, 3);
+        let paths = dijkstra_all(
+            &(IVec2::new(1, 1), Direction::East),
+            reindeer(&maze),
+            |&(p, _)| p == end,
+        )
+        .expect("maze is solvable");
+        assert_eq!(2006, paths.cost());
+        // Both ways round the pillar cost the same, and the end is reached facing east or south.
+        assert_eq!(2, paths.count());
+        assert_eq!(2, paths.paths().len());
+        let tiles = paths
+            .nodes()
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect::<HashSet<_>>();
+        assert_eq!(11, tiles.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bfs_all_counts_every_path() -> miette::Result<()> {
+        let open_grid = Grid::new(3, 3, '.');
+        let corner = IVec2::new(2, 2);
+        let paths = bfs_all(&IVec2::ZERO, open(&open_grid), |&p| p == corner).expect("reachable");
+        assert_eq!(4, paths.cost());
+        assert_eq!(6, paths.count());
+        assert_eq!(6, paths.count_to(&corner));
+        assert_eq!(9, paths.nodes().len());
+        assert_eq!(&[corner], paths.targets());
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_targets_at_best_cost() {
+        // From 0, both 2 and -2 are two steps away while 3 is three.
+        let paths = bfs_all(&0i32, |&n| [n - 1, n + 1], |&n| n.abs() >= 2).expect("reachable");
+        let mut targets = paths.targets().to_vec();
+        targets.sort();
+        assert_eq!(vec![-2, 2], targets);
+        assert_eq!(2, paths.count());
+        assert_eq!(&[1], paths.predecessors(&2));
+    }
+
+    #[test]
+    fn test_dijkstra_all_zero_cost_cycle() {
+        // 1 and 2 swap for free, so each ties with the other once both are reached.
+        let paths = dijkstra_all(
+            &0u8,
+            |&n| match n {
+                0 => vec![(1, 1), (2, 1)],
+                1 => vec![(2, 0), (3, 1)],
+                2 => vec![(1, 0), (3, 1)],
+                _ => vec![],
+            },
+            |&n| n == 3,
+        )
+        .expect("reachable");
+        assert_eq!(2, paths.cost());
+        assert_eq!(3, paths.count());
+        assert_eq!(paths.count() as usize, paths.paths().len());
+    }
+
+    #[test]
+    fn test_count_long_path() {
+        let end = 100_000;
+        let paths = bfs_all(&0u32, |&n| [n + 1], |&n| n == end).expect("reachable");
+        assert_eq!(end as usize, paths.cost());
+        assert_eq!(1, paths.count());
+    }
+}
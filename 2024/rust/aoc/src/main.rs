@@ -0,0 +1,324 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use aoc::registry::{self, Day, Params, DAYS};
+use aoc_answer::db::{AnswerDb, Verdict};
+use aoc_input::{
+    fetch::{Fetched, Fetcher},
+    file_name, input_dir, InputError, Source,
+};
+use clap::{Parser, Subcommand};
+use miette::{miette, Context};
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solutions for one day, or for every day when no day is given.
+    Run {
+        /// Day of the puzzle (1-25).
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part of the puzzle (1 or 2); both parts are run when omitted.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from this file (or `-` for stdin) instead of `input_dayNN.txt`.
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Override a solver parameter, e.g. `--param blinks=25`.
+        #[arg(short, long = "param", requires = "day", value_parser = parse_param)]
+        params: Vec<(String, i64)>,
+    },
+    /// List every registered day and the parameters its parts accept.
+    List,
+    /// Download a day's puzzle input using the SESSION cookie; existing inputs are left alone.
+    Fetch {
+        /// Day of the puzzle (1-25).
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Fetch from this server instead of `AOC_BASE_URL` or adventofcode.com.
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Check every solution against its real input and the recorded answers.
+    ///
+    /// Inputs are always `input_dayNN.txt` in the input directory; `AOC_INPUT` is ignored.
+    Verify {
+        /// Only verify this day (1-25).
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Answers file to use instead of `AOC_ANSWERS` or `answers.json` next to the inputs.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Record the answer for every part that has none yet.
+        #[arg(long)]
+        save: bool,
+    },
+}
+
+/// Path to the answers file `aoc verify` checks against.
+fn answers_path() -> PathBuf {
+    AnswerDb::default_path(&input_dir())
+}
+
+fn parse_param(arg: &str) -> Result<(String, i64), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got `{arg}`"))?;
+    let value = value
+        .parse::<i64>()
+        .map_err(|e| format!("invalid value for `{name}`: {e}"))?;
+    Ok((name.to_string(), value))
+}
+
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    match Cli::parse().command {
+        Command::Run {
+            day,
+            part,
+            input,
+            params,
+        } => {
+            let mut overrides = Params::new();
+            for (name, value) in params {
+                overrides.set(&name, value);
+            }
+
+            let days =
+                match day {
+                    Some(day) => vec![registry::find(day)
+                        .ok_or_else(|| miette!("day {day} is not registered"))?],
+                    None => DAYS.iter().collect(),
+                };
+
+            let mut failures = 0;
+            for day in days {
+                failures += run_day(day, part, input.as_deref(), &overrides);
+            }
+
+            match failures {
+                0 => Ok(()),
+                n => Err(miette!("{n} part(s) failed")),
+            }
+        }
+        Command::List => {
+            for day in DAYS.iter() {
+                for part in 1..=2 {
+                    match day.part(part) {
+                        Some(solver) => {
+                            let params = solver
+                                .params
+                                .iter()
+                                .map(|p| format!("{}={}", p.name, p.default))
+                                .collect::<Vec<_>>()
+                                .join(" ");
+                            println!("Day {:02} Part {part}: {params}", day.day);
+                        }
+                        None => println!("Day {:02} Part {part}: not solved", day.day),
+                    }
+                }
+            }
+            Ok(())
+        }
+        Command::Fetch { day, base_url } => {
+            let mut fetcher = Fetcher::from_env()?;
+            if let Some(url) = base_url {
+                fetcher = fetcher.with_base_url(url);
+            }
+            match fetcher.fetch(day)? {
+                Fetched::Downloaded(path) => println!("Day {day:02}: saved {}", path.display()),
+                Fetched::Cached(path) => {
+                    println!(
+                        "Day {day:02}: {} already exists, not fetching",
+                        path.display()
+                    )
+                }
+            }
+            Ok(())
+        }
+        Command::Verify { day, answers, save } => {
+            let path = answers.unwrap_or_else(answers_path);
+            let mut db = AnswerDb::load(&path)?;
+
+            let days =
+                match day {
+                    Some(day) => vec![registry::find(day)
+                        .ok_or_else(|| miette!("day {day} is not registered"))?],
+                    None => DAYS.iter().collect(),
+                };
+
+            let mut tally = Tally::default();
+            for day in days {
+                verify_day(day, &mut db, save, &mut tally);
+            }
+
+            println!(
+                "{} passed, {} failed, {} unknown",
+                tally.pass, tally.fail, tally.unknown
+            );
+            if save && tally.recorded > 0 {
+                db.save(&path)?;
+                println!(
+                    "recorded {} answer(s) in {}",
+                    tally.recorded,
+                    path.display()
+                );
+            }
+
+            match tally.fail {
+                0 => Ok(()),
+                n => Err(miette!(
+                    "{n} part(s) failed or do not match their recorded answer"
+                )),
+            }
+        }
+    }
+}
+
+/// Run the requested parts of a single day and return how many of them failed.
+fn run_day(day: &Day, part: Option<u8>, input: Option<&Path>, overrides: &Params) -> usize {
+    let source = input
+        .map(Source::from_arg)
+        .unwrap_or_else(|| Source::for_day(day.day));
+    let file = match source.read() {
+        Ok(file) => file,
+        Err(e) => {
+            println!("Day {:02}: no input", day.day);
+            eprintln!("{:?}", miette::Report::new(e));
+            return 1;
+        }
+    };
+
+    let mut failures = 0;
+    for p in part.map(|p| vec![p]).unwrap_or_else(|| vec![1, 2]) {
+        let Some(solver) = day.part(p) else {
+            if part.is_some() {
+                println!("Day {:02} Part {p}: not solved", day.day);
+                failures += 1;
+            }
+            continue;
+        };
+
+        let start = Instant::now();
+        let result = solver
+            .run(&file, overrides)
+            .wrap_err_with(|| format!("day {} part {p}", day.day));
+        let elapsed = start.elapsed();
+
+        match result {
+            Ok(answer) if answer.is_multiline() => {
+                println!("Day {:02} Part {p} ({elapsed:.2?}):\n{answer}", day.day)
+            }
+            Ok(answer) => println!("Day {:02} Part {p}: {answer} ({elapsed:.2?})", day.day),
+            Err(e) => {
+                println!("Day {:02} Part {p}: failed ({elapsed:.2?})", day.day);
+                eprintln!("{e:?}");
+                failures += 1;
+            }
+        }
+    }
+    failures
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    pass: usize,
+    fail: usize,
+    unknown: usize,
+    recorded: usize,
+}
+
+/// Run every solved part of a day on its real input and compare it with the recorded answer.
+///
+/// With `save`, parts that have no recorded answer yet get the one they produced.
+fn verify_day(day: &Day, db: &mut AnswerDb, save: bool, tally: &mut Tally) {
+    let parts = (1..=2)
+        .filter(|&p| day.part(p).is_some())
+        .collect::<Vec<_>>();
+    // Always the day's own input: `AOC_INPUT` names one file, which would be fed to every day.
+    let source = Source::File(input_dir().join(file_name(day.day)));
+    let file = match source.read() {
+        Ok(file) => file,
+        Err(InputError::Missing { .. }) => {
+            for p in parts {
+                println!("Day {:02} Part {p}: unknown, no input", day.day);
+                tally.unknown += 1;
+            }
+            return;
+        }
+        Err(e) => {
+            for p in &parts {
+                println!("Day {:02} Part {p}: FAIL, could not read input", day.day);
+                tally.fail += 1;
+            }
+            eprintln!("{:?}", miette::Report::new(e));
+            return;
+        }
+    };
+
+    for p in parts {
+        let solver = day.part(p).expect("only solved parts are verified");
+        let start = Instant::now();
+        let result = solver.run(&file, &Params::new());
+        let elapsed = start.elapsed();
+
+        let answer = match result {
+            Ok(answer) => answer,
+            Err(e) => {
+                println!("Day {:02} Part {p}: FAIL, errored ({elapsed:.2?})", day.day);
+                eprintln!("{e:?}");
+                tally.fail += 1;
+                continue;
+            }
+        };
+
+        if solver.report {
+            println!(
+                "Day {:02} Part {p}: unknown, a report is never recorded ({elapsed:.2?})",
+                day.day
+            );
+            tally.unknown += 1;
+            continue;
+        }
+
+        match db.check(day.day, p, &answer) {
+            Verdict::Pass => {
+                println!("Day {:02} Part {p}: pass ({elapsed:.2?})", day.day);
+                tally.pass += 1;
+            }
+            Verdict::Fail { expected } => {
+                println!(
+                    "Day {:02} Part {p}: FAIL, got {answer} but expected {expected} ({elapsed:.2?})",
+                    day.day
+                );
+                tally.fail += 1;
+            }
+            Verdict::Unknown if save => {
+                println!(
+                    "Day {:02} Part {p}: unknown, recording {answer} ({elapsed:.2?})",
+                    day.day
+                );
+                db.insert(day.day, p, answer);
+                tally.unknown += 1;
+                tally.recorded += 1;
+            }
+            Verdict::Unknown => {
+                println!(
+                    "Day {:02} Part {p}: unknown, got {answer} ({elapsed:.2?})",
+                    day.day
+                );
+                tally.unknown += 1;
+            }
+        }
+    }
+}
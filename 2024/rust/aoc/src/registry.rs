@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+
+use aoc_answer::Answer;
+use miette::miette;
+
+/// Signature every registered solution is adapted to.
+pub type SolveFn = fn(&str, &Params) -> miette::Result<Answer>;
+
+/// A tunable value a solution takes besides its input, e.g. the number of blinks for day 11.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+}
+
+/// Parameter values handed to a [`SolveFn`], one for every [`Param`] the solver declares.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params(HashMap<String, i64>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.0.insert(name.to_string(), value);
+    }
+
+    /// Look up a parameter the solver declared.
+    ///
+    /// Panics when `name` was never declared, which is a mistake in the registry.
+    pub fn get(&self, name: &str) -> i64 {
+        *self
+            .0
+            .get(name)
+            .unwrap_or_else(|| panic!("parameter `{name}` should be declared by the solver"))
+    }
+
+    /// [`Params::get`] converted to the type the solution takes, e.g. a `usize` that must not
+    /// be negative.
+    pub fn get_as<T: TryFrom<i64>>(&self, name: &str) -> miette::Result<T> {
+        let value = self.get(name);
+        T::try_from(value).map_err(|_| {
+            miette!(
+                "parameter `{name}` is out of range for {}: {value}",
+                std::any::type_name::<T>()
+            )
+        })
+    }
+}
+
+/// One part of a day's puzzle.
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub solve: SolveFn,
+    pub params: &'static [Param],
+    /// The answer is a report that changes with more than the input, like day 25's star count,
+    /// so `aoc verify` never records or checks it.
+    pub report: bool,
+}
+
+impl Solver {
+    const fn new(solve: SolveFn) -> Self {
+        Self {
+            solve,
+            params: &[],
+            report: false,
+        }
+    }
+
+    const fn with_params(solve: SolveFn, params: &'static [Param]) -> Self {
+        Self {
+            solve,
+            params,
+            report: false,
+        }
+    }
+
+    const fn report(solve: SolveFn) -> Self {
+        Self {
+            solve,
+            params: &[],
+            report: true,
+        }
+    }
+
+    /// Fill in defaults for every declared parameter and reject overrides the solver does not know.
+    pub fn resolve_params(&self, overrides: &Params) -> miette::Result<Params> {
+        if let Some(unknown) = overrides
+            .0
+            .keys()
+            .find(|k| !self.params.iter().any(|p| p.name == k.as_str()))
+        {
+            let known = self.params.iter().map(|p| p.name).collect::<Vec<_>>();
+            return Err(miette!(
+                "unknown parameter `{unknown}`, expected one of {known:?}"
+            ));
+        }
+
+        let mut params = Params::new();
+        for p in self.params {
+            params.set(
+                p.name,
+                overrides.0.get(p.name).copied().unwrap_or(p.default),
+            );
+        }
+        Ok(params)
+    }
+
+    pub fn run(&self, input: &str, overrides: &Params) -> miette::Result<Answer> {
+        let params = self.resolve_params(overrides)?;
+        (self.solve)(input, &params)
+    }
+}
+
+/// Both parts of a day's puzzle; a part is `None` until it has been solved.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub part1: Option<Solver>,
+    pub part2: Option<Solver>,
+}
+
+impl Day {
+    pub fn part(&self, part: u8) -> Option<&Solver> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Look up a day in [`DAYS`].
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+pub static DAYS: [Day; 25] = [
+    Day {
+        day: 1,
+        part1: Some(Solver::new(|input, _| day01::part1(input))),
+        part2: Some(Solver::new(|input, _| day01::part2(input))),
+    },
+    Day {
+        day: 2,
+        part1: Some(Solver::new(|input, _| day02::part1(input))),
+        part2: Some(Solver::new(|input, _| day02::part2(input))),
+    },
+    Day {
+        day: 3,
+        part1: Some(Solver::new(|input, _| day03::part1(input))),
+        part2: Some(Solver::new(|input, _| day03::part2(input))),
+    },
+    Day {
+        day: 4,
+        part1: Some(Solver::new(|input, _| day04::part1(input))),
+        part2: Some(Solver::new(|input, _| day04::part2(input))),
+    },
+    Day {
+        day: 5,
+        part1: Some(Solver::new(|input, _| day05::part1::process(input))),
+        part2: Some(Solver::new(|input, _| day05::part2::process(input))),
+    },
+    Day {
+        day: 6,
+        part1: Some(Solver::new(|input, _| day06::part1::process(input))),
+        part2: Some(Solver::new(|input, _| day06::part2::process(input))),
+    },
+    Day {
+        day: 7,
+        part1: Some(Solver::new(|mut input, _| {
+            day07::part1::process(&mut input)
+        })),
+        part2: Some(Solver::new(|mut input, _| {
+            day07::part2::process(&mut input)
+        })),
+    },
+    Day {
+        day: 8,
+        part1: Some(Solver::new(|mut input, _| {
+            day08::part1::process(&mut input)
+        })),
+        part2: Some(Solver::new(|mut input, _| {
+            day08::part2::process(&mut input)
+        })),
+    },
+    Day {
+        day: 9,
+        part1: Some(Solver::new(|input, _| day09::part1::process(input))),
+        part2: Some(Solver::new(|input, _| day09::part2::process(input))),
+    },
+    Day {
+        day: 10,
+        part1: Some(Solver::new(|input, _| day10::part1::process(input))),
+        part2: Some(Solver::new(|input, _| day10::part2::process(input))),
+    },
+    Day {
+        day: 11,
+        part1: Some(Solver::new(|mut input, _| {
+            day11::part1::process(&mut input)
+        })),
+        part2: Some(Solver::with_params(
+            |input, p| day11::part2::process(input, p.get_as::<u32>("blinks")?),
+            &[Param {
+                name: "blinks",
+                default: 75,
+            }],
+        )),
+    },
+    Day {
+        day: 12,
+        part1: Some(Solver::new(|input, _| day12::part1::process(input))),
+        part2: Some(Solver::new(|input, _| day12::part2::process(input))),
+    },
+    Day {
+        day: 13,
+        part1: Some(Solver::new(|mut input, _| {
+            day13::part1::process(&mut input)
+        })),
+        part2: Some(Solver::new(|mut input, _| {
+            day13::part2::process(&mut input)
+        })),
+    },
+    Day {
+        day: 14,
+        part1: Some(Solver::with_params(
+            |mut input, p| {
+                day14::part1::process(
+                    &mut input,
+                    p.get_as::<i32>("width")?,
+                    p.get_as::<i32>("height")?,
+                )
+            },
+            &[
+                Param {
+                    name: "width",
+                    default: 101,
+                },
+                Param {
+                    name: "height",
+                    default: 103,
+                },
+            ],
+        )),
+        part2: Some(Solver::new(|mut input, _| {
+            day14::part2::process(&mut input)
+        })),
+    },
+    Day {
+        day: 15,
+        part1: Some(Solver::new(|mut input, _| {
+            day15::part1::process(&mut input)
+        })),
+        part2: Some(Solver::new(|mut input, _| {
+            day15::part2::process(&mut input)
+        })),
+    },
+    Day {
+        day: 16,
+        part1: Some(Solver::new(|input, _| day16::part1::process(input))),
+        part2: Some(Solver::new(|input, _| day16::part2::process(input))),
+    },
+    Day {
+        day: 17,
+        part1: Some(Solver::new(|mut input, _| {
+            day17::part1::process(&mut input)
+        })),
+        part2: Some(Solver::new(|input, _| day17::part2::process(input))),
+    },
+    Day {
+        day: 18,
+        part1: Some(Solver::with_params(
+            |mut input, p| {
+                day18::part1::process(
+                    &mut input,
+                    p.get_as::<usize>("bytes")?,
+                    p.get_as::<usize>("width")?,
+                )
+            },
+            &[
+                Param {
+                    name: "bytes",
+                    default: 1024,
+                },
+                Param {
+                    name: "width",
+                    default: 70,
+                },
+            ],
+        )),
+        part2: Some(Solver::with_params(
+            |mut input, p| day18::part2::process(&mut input, p.get_as::<usize>("width")?),
+            &[Param {
+                name: "width",
+                default: 70,
+            }],
+        )),
+    },
+    Day {
+        day: 19,
+        part1: Some(Solver::new(|mut input, _| {
+            day19::part1::process(&mut input)
+        })),
+        part2: Some(Solver::new(|mut input, _| {
+            day19::part2::process(&mut input)
+        })),
+    },
+    Day {
+        day: 20,
+        part1: Some(Solver::with_params(
+            |input, p| day20::part1::process(input, p.get_as::<usize>("savings")?),
+            &[Param {
+                name: "savings",
+                default: 100,
+            }],
+        )),
+        part2: Some(Solver::with_params(
+            |input, p| {
+                day20::part2::process(
+                    input,
+                    p.get_as::<usize>("cheat")?,
+                    p.get_as::<usize>("savings")?,
+                )
+            },
+            &[
+                Param {
+                    name: "cheat",
+                    default: 20,
+                },
+                Param {
+                    name: "savings",
+                    default: 100,
+                },
+            ],
+        )),
+    },
+    Day {
+        day: 21,
+        part1: Some(Solver::new(|input, _| day21::part1::process(input))),
+        part2: Some(Solver::with_params(
+            |input, p| day21::part2::process(input, p.get_as::<usize>("robots")?),
+            &[Param {
+                name: "robots",
+                default: 25,
+            }],
+        )),
+    },
+    Day {
+        day: 22,
+        part1: Some(Solver::new(|input, _| day22::part1::process(input))),
+        part2: Some(Solver::new(|input, _| day22::part2::process(input))),
+    },
+    Day {
+        day: 23,
+        part1: Some(Solver::new(|input, _| day23::part1::process(input))),
+        part2: Some(Solver::new(|input, _| day23::part2::process(input))),
+    },
+    Day {
+        day: 24,
+        part1: Some(Solver::new(|mut input, _| {
+            day24::part1::process(&mut input)
+        })),
+        part2: Some(Solver::new(|input, _| day24::part2::process(input))),
+    },
+    Day {
+        day: 25,
+        part1: Some(Solver::new(|mut input, _| {
+            day25::part1::process(&mut input)
+        })),
+        part2: Some(Solver::report(|input, _| day25::part2::process(input))),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_complete() {
+        let days = DAYS.iter().map(|d| d.day).collect::<Vec<u8>>();
+        assert_eq!((1..=25).collect::<Vec<u8>>(), days);
+    }
+
+    #[test]
+    fn test_only_day_25_part_2_is_a_report() {
+        let reports = DAYS
+            .iter()
+            .flat_map(|d| [(d.day, 1), (d.day, 2)])
+            .filter(|&(day, part)| {
+                find(day)
+                    .and_then(|d| d.part(part))
+                    .is_some_and(|s| s.report)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(25, 2)], reports);
+    }
+
+    #[test]
+    fn test_run_legacy_day() -> miette::Result<()> {
+        let input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+        let solver = find(1)
+            .and_then(|d| d.part(2))
+            .expect("day 1 is registered");
+        assert_eq!("31", solver.run(input, &Params::new())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_with_params() -> miette::Result<()> {
+        let input = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+        let mut overrides = Params::new();
+        overrides.set("width", 11);
+        overrides.set("height", 7);
+        let solver = find(14)
+            .and_then(|d| d.part(1))
+            .expect("day 14 is registered");
+        assert_eq!("12", solver.run(input, &overrides)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_param() {
+        let mut overrides = Params::new();
+        overrides.set("blinkz", 25);
+        let solver = find(11)
+            .and_then(|d| d.part(2))
+            .expect("day 11 is registered");
+        assert!(solver.resolve_params(&overrides).is_err());
+    }
+
+    #[test]
+    fn test_negative_param() {
+        let mut overrides = Params::new();
+        overrides.set("blinks", -1);
+        let solver = find(11)
+            .and_then(|d| d.part(2))
+            .expect("day 11 is registered");
+        let err = solver.run("125 17", &overrides).unwrap_err();
+        assert!(err.to_string().contains("`blinks` is out of range"));
+    }
+}
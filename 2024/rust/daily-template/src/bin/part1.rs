@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../../../input_{{crate_name}}.txt");
-    let result = process(file).context("process part 1")?;
+    let file = aoc_input::load_cli({{crate_name | remove: "day"}})?;
+    let result = process(&file).context("process part 1")?;
     println!("Part 1: {}", result);
     Ok(())
 }
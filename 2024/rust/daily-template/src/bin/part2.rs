@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../../../input_{{crate_name}}.txt");
-    let result = process(file).context("process part 2")?;
+    let file = aoc_input::load_cli({{crate_name | remove: "day"}})?;
+    let result = process(&file).context("process part 2")?;
     println!("Part 2: {}", result);
     Ok(())
 }
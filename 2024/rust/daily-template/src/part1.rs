@@ -1,5 +1,7 @@
+use aoc_answer::Answer;
+
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String> {
+pub fn process(_input: &str) -> miette::Result<Answer> {
     todo!("{{crate_name}} - part 1");
 }
 
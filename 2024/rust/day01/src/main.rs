@@ -1,11 +1,14 @@
+use day01::{part1, part2};
 use miette::miette;
 use std::{
-    collections::HashMap,
     io::{self, Write},
+    path::Path,
 };
 
 fn main() -> miette::Result<()> {
-    let input = include_str!("../input.txt");
+    // Day 1 kept its input in the crate before the shared input directory existed.
+    let legacy = Path::new(env!("CARGO_MANIFEST_DIR")).join("input.txt");
+    let input = aoc_input::load_cli_or(1, legacy)?;
 
     writeln!(io::stdout(), "Part1: {}", part1(&input)?)
         .map_err(|e| miette!("failed to write {}", e))?;
@@ -13,76 +16,3 @@ fn main() -> miette::Result<()> {
         .map_err(|e| miette!("failed to write {}", e))?;
     Ok(())
 }
-
-fn part1(input: &str) -> miette::Result<String> {
-    let mut left = vec![];
-    let mut right = vec![];
-
-    for line in input.lines() {
-        let mut items = line.split_whitespace();
-        left.push(items.next().unwrap().parse::<i32>().unwrap());
-        right.push(items.next().unwrap().parse::<i32>().unwrap());
-    }
-
-    left.sort();
-    right.sort();
-
-    let diffs = left
-        .into_iter()
-        .zip(right)
-        .map(|(l, r)| l.abs_diff(r))
-        .sum::<u32>();
-
-    Ok(diffs.to_string())
-}
-
-fn part2(input: &str) -> miette::Result<String> {
-    let mut left = vec![];
-    let mut right: HashMap<&str, i32> = HashMap::new();
-
-    for line in input.lines() {
-        let mut items = line.split_whitespace();
-        left.push(items.next().unwrap());
-        *right.entry(items.next().unwrap()).or_insert(0) += 1;
-    }
-
-    let diffs = left
-        .into_iter()
-        .map(|l| {
-            let cnt = right.get(l).unwrap_or(&0);
-            l.parse::<i32>().unwrap() * cnt
-        })
-        .sum::<i32>();
-
-    Ok(diffs.to_string())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() -> miette::Result<()> {
-        let input = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
-
-        assert_eq!("11", part1(&input)?);
-        Ok(())
-    }
-
-    #[test]
-    fn test_part2() -> miette::Result<()> {
-        let input = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
-        assert_eq!("31", part2(&input)?);
-        Ok(())
-    }
-}
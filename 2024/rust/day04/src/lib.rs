@@ -0,0 +1,86 @@
+use aoc_answer::Answer;
+use aoc_grid::{Direction, Grid};
+use glam::IVec2;
+
+// Scan the grid for 'X' and look for the rest of "XMAS" in all eight directions:
+//                     X         M               A               S
+//   - forward      (i, j), (i + 1, j),     (i + 2, j),      (i + 3, j)
+//   - forward down (i, j), (i + 1, j + 1), (i + 2, j + 2),  (i + 3, j + 3)
+//   - ...
+// Positions off the edge of the grid never match.
+
+pub fn part1(input: &str) -> miette::Result<Answer> {
+    let g = Grid::parse(input)?;
+
+    let xmas_cnt = g
+        .find_all(&'X')
+        .flat_map(|pos| Direction::ALL.map(|dir| (pos, dir.to_ivec2())))
+        .filter(|&(pos, dir)| {
+            "MAS"
+                .chars()
+                .zip(1..)
+                .all(|(c, i)| g.get(pos + dir * i) == Some(&c))
+        })
+        .count();
+
+    Ok(xmas_cnt.into())
+}
+
+// Look for an 'A' where both diagonals through it read "MAS" in either direction.
+fn is_mas(g: &Grid<char>, a: IVec2, b: IVec2) -> bool {
+    matches!(
+        (g.get(a), g.get(b)),
+        (Some('M'), Some('S')) | (Some('S'), Some('M'))
+    )
+}
+
+pub fn part2(input: &str) -> miette::Result<Answer> {
+    let g = Grid::parse(input)?;
+
+    let xmas_cnt = g
+        .find_all(&'A')
+        .filter(|&pos| {
+            is_mas(&g, pos + Direction::NorthWest, pos + Direction::SouthEast)
+                && is_mas(&g, pos + Direction::NorthEast, pos + Direction::SouthWest)
+        })
+        .count();
+
+    Ok(xmas_cnt.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> miette::Result<()> {
+        let input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+        assert_eq!("18", part1(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> miette::Result<()> {
+        let input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+        assert_eq!("9", part2(input)?);
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use std::collections::HashSet;
 
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use itertools::all;
-use miette::Context;
 use winnow::{
     ascii::{digit1, line_ending},
     combinator::{repeat, separated, separated_pair, terminated},
@@ -9,8 +10,8 @@ use winnow::{
 };
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let (_report_order, updated_reports) = parse(input).context("should parse input")?;
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let (_report_order, updated_reports) = parse(input)?;
     let mut total = 0;
     for report in updated_reports {
         let mut in_order = Vec::new();
@@ -29,7 +30,7 @@ pub fn process(input: &str) -> miette::Result<String> {
         }
     }
 
-    Ok(total.to_string())
+    Ok(total.into())
 }
 
 fn parse_page_order(input: &mut &str) -> PResult<Vec<(u32, u32)>> {
@@ -50,18 +51,16 @@ fn parse_report_update(input: &mut &str) -> PResult<Vec<u32>> {
 type PageOrder = HashSet<(u32, u32)>;
 type UpdatedReport = Vec<Vec<u32>>;
 
-fn parse(input: &str) -> miette::Result<(PageOrder, UpdatedReport)> {
-    let mut input = input;
-    let page_order: PageOrder =
-        HashSet::from_iter(parse_page_order.parse_next(&mut input).unwrap());
-
-    let _: PResult<Vec<_>> = repeat(0.., line_ending).parse_next(&mut input);
-
-    let reports: Vec<Vec<u32>> = repeat(0.., terminated(parse_report_update, line_ending))
-        .parse_next(&mut input)
-        .unwrap();
-
-    Ok((page_order, reports))
+fn parse(input: &str) -> Result<(PageOrder, UpdatedReport), ParseError> {
+    let (page_order, reports) = aoc_parse::parse(
+        input,
+        separated_pair(
+            parse_page_order,
+            line_ending,
+            separated(1.., parse_report_update, line_ending),
+        ),
+    )?;
+    Ok((HashSet::from_iter(page_order), reports))
 }
 
 #[cfg(test)]
@@ -101,4 +100,10 @@ mod tests {
         assert_eq!("143", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error_points_at_input() {
+        let err = parse("47|53\n\n75,x7\n").unwrap_err();
+        assert_eq!((3, 3), (err.line(), err.column()));
+    }
 }
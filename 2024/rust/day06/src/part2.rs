@@ -1,95 +1,45 @@
 use std::collections::HashSet;
 
+use aoc_answer::Answer;
+use aoc_grid::{Direction, Grid};
 use glam::IVec2;
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let parsed = parse(input);
-    let n_cols = parsed.iter().map(|p| p.0.x).max().unwrap();
-    let n_rows = parsed.iter().map(|p| p.0.y).max().unwrap();
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let grid = Grid::parse(input)?;
+    let start = grid.find(&'^').expect("should always have a guard");
 
     let mut causes_loop: HashSet<IVec2> = HashSet::new();
-    for empty_loc in parsed.iter().filter(|p| p.1 == '.').map(|p| p.0) {
-        let mut walls = parsed
-            .iter()
-            .filter(|p| p.1 == '#')
-            .map(|p| p.0)
-            .collect::<HashSet<IVec2>>();
+    for empty_loc in grid.find_all(&'.') {
+        let is_wall = |pos: IVec2| pos == empty_loc || grid.get(pos) == Some(&'#');
 
-        walls.insert(empty_loc.clone());
+        let mut direction = Direction::North;
+        let mut guard = start;
 
-        let mut direction = Direction::Up;
-        let mut guard = parsed
-            .iter()
-            .filter(|p| p.1 == '^')
-            .map(|p| p.0)
-            .last()
-            .unwrap();
-
-        let mut visited = HashSet::from([(direction.clone(), guard.clone())]);
+        let mut visited = HashSet::from([(direction, guard)]);
         loop {
-            let peek = guard + direction.step();
+            let peek = guard + direction;
 
-            if walls.contains(&peek) {
+            if is_wall(peek) {
                 direction = direction.turn_right();
                 continue;
             }
 
-            guard += direction.step();
+            guard = peek;
 
-            if !(0..=n_cols).contains(&guard.x) || !(0..=n_rows).contains(&guard.y) {
+            if !grid.in_bounds(guard) {
                 break;
             }
 
-            if visited.contains(&(direction.clone(), guard.clone())) {
+            if visited.contains(&(direction, guard)) {
                 causes_loop.insert(empty_loc);
                 break;
             }
-            visited.insert((direction.clone(), guard.clone()));
-        }
-    }
-
-    Ok(causes_loop.len().to_string())
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn turn_right(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
-
-    fn step(&self) -> IVec2 {
-        match self {
-            Direction::Up => IVec2::NEG_Y,
-            Direction::Right => IVec2::X,
-            Direction::Down => IVec2::Y,
-            Direction::Left => IVec2::NEG_X,
+            visited.insert((direction, guard));
         }
     }
-}
 
-fn parse(input: &str) -> Vec<(IVec2, char)> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(r, l)| {
-            l.chars()
-                .enumerate()
-                .map(move |(c, v)| (IVec2::new(c.clone() as i32, r.clone() as i32), v.to_owned()))
-        })
-        .collect()
+    Ok(causes_loop.len().into())
 }
 
 #[cfg(test)]
@@ -98,7 +48,7 @@ mod tests {
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        let mut input = "....#.....
+        let input = "....#.....
 .........#
 ..........
 ..#.......
@@ -108,7 +58,7 @@ mod tests {
 ........#.
 #.........
 ......#...";
-        assert_eq!("6", process(&mut input)?);
+        assert_eq!("6", process(input)?);
         Ok(())
     }
 }
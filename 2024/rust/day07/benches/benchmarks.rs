@@ -6,17 +6,13 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(&mut divan::black_box(include_str!(
-        "../../../input_day07.txt"
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_input::load(7).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| part1::process(divan::black_box(&mut input.as_str())).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(&mut divan::black_box(include_str!(
-        "../../../input_day07.txt"
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_input::load(7).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| part2::process(divan::black_box(&mut input.as_str())).unwrap());
 }
@@ -1,12 +1,12 @@
-use day07::part2::process;
+use day07::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let mut file = include_str!("../../../../input_day07.txt");
-    let result = process(&mut file).context("process part 2")?;
-    println!("Part 2: {}", result);
+    let file = aoc_input::load_cli(7)?;
+    let result = process(&mut file.as_str()).context("process part 1")?;
+    println!("Part 1: {}", result);
     Ok(())
 }
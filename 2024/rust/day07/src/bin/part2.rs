@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let mut file = include_str!("../../../../input_day07.txt");
-    let result = process(&mut file).context("process part 2")?;
+    let file = aoc_input::load_cli(7)?;
+    let result = process(&mut file.as_str()).context("process part 2")?;
     println!("Part 2: {}", result);
     Ok(())
 }
@@ -1,3 +1,5 @@
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use itertools::{repeat_n, Itertools};
 use winnow::{
     ascii::{dec_int, line_ending, space1},
@@ -6,27 +8,24 @@ use winnow::{
 };
 
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let result = parse(input)
-        .unwrap()
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let result = parse(input)?
         .into_iter()
         .filter(|(r, vs)| {
             let totals = repeat_n([Instruction::Add, Instruction::Mul], vs.len() - 1)
                 .multi_cartesian_product()
                 .map(|ops| {
-                    ops.iter()
-                        .enumerate()
-                        .fold(vs[0] as i64, |acc, (i, op)| match op {
-                            Instruction::Add => acc + vs[i + 1] as i64,
-                            Instruction::Mul => acc * vs[i + 1] as i64,
-                        })
+                    ops.iter().enumerate().fold(vs[0], |acc, (i, op)| match op {
+                        Instruction::Add => acc + vs[i + 1],
+                        Instruction::Mul => acc * vs[i + 1],
+                    })
                 })
                 .collect::<Vec<i64>>();
-            totals.contains(&(*r as i64))
+            totals.contains(r)
         })
         .map(|(r, _)| r)
         .sum::<i64>();
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
 #[derive(Debug, Clone)]
@@ -39,8 +38,8 @@ fn parse_callibrations(input: &mut &str) -> winnow::PResult<(i64, Vec<i64>)> {
     separated_pair(dec_int, ": ", separated(1.., dec_int::<_, i64, _>, space1)).parse_next(input)
 }
 
-fn parse(input: &mut &str) -> winnow::PResult<Vec<(i64, Vec<i64>)>> {
-    separated(1.., parse_callibrations, line_ending).parse_next(input)
+fn parse(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    aoc_parse::parse(input, separated(1.., parse_callibrations, line_ending))
 }
 
 #[cfg(test)]
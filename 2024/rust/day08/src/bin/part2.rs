@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let mut file = include_str!("../../../../input_day08.txt");
-    let result = process(&mut file).context("process part 1")?;
-    println!("Part 1: {}", result);
+    let file = aoc_input::load_cli(8)?;
+    let result = process(&mut file.as_str()).context("process part 2")?;
+    println!("Part 2: {}", result);
     Ok(())
 }
@@ -1,3 +1,2 @@
 pub mod part1;
 pub mod part2;
-pub mod parse_testing;
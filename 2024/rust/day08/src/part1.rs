@@ -1,14 +1,18 @@
 use std::collections::HashSet;
 
+use aoc_answer::Answer;
+use aoc_parse::Scanner;
 use glam::IVec2;
 use itertools::Itertools;
 
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let (n_rows, n_cols, results) = parse(input);
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let antennas = Scanner::default().scan(input)?;
 
-    let total = results
-        .into_iter()
+    let total = antennas
+        .tokens
+        .iter()
+        .copied()
         .into_group_map()
         .into_iter()
         .fold(
@@ -20,20 +24,12 @@ pub fn process(input: &mut &str) -> miette::Result<String> {
                     let delta = a - b;
 
                     let antinode1 = a + delta;
-                    if (antinode1.x >= 0)
-                        && (antinode1.x < n_cols)
-                        && (antinode1.y >= 0)
-                        && (antinode1.y < n_rows)
-                    {
+                    if antennas.in_bounds(antinode1) {
                         acc.insert(antinode1);
                     }
 
                     let antinode2 = b - delta;
-                    if (antinode2.x >= 0)
-                        && (antinode2.x < n_cols)
-                        && (antinode2.y >= 0)
-                        && (antinode2.y < n_rows)
-                    {
+                    if antennas.in_bounds(antinode2) {
                         acc.insert(antinode2);
                     }
                 }
@@ -42,28 +38,7 @@ pub fn process(input: &mut &str) -> miette::Result<String> {
         )
         .len();
 
-    Ok(total.to_string())
-}
-
-fn parse(input: &mut &str) -> (i32, i32, Vec<(char, IVec2)>) {
-    let n_rows = input.lines().count() as i32;
-    let n_cols = input.lines().next().unwrap().len() as i32;
-
-    let data = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row, line)| {
-            line.chars()
-                .enumerate()
-                .filter_map(|(col, val)| match val {
-                    '.' => None,
-                    _ => Some((val, IVec2::new(col as i32, row as i32))),
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect();
-
-    (n_rows, n_cols, data)
+    Ok(total.into())
 }
 
 #[cfg(test)]
@@ -1,8 +1,9 @@
+use aoc_answer::Answer;
 use itertools::{repeat_n, Itertools};
 use std::collections::VecDeque;
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
+pub fn process(input: &str) -> miette::Result<Answer> {
     let mut queue = input
         .trim()
         .chars()
@@ -53,13 +54,13 @@ pub fn process(input: &str) -> miette::Result<String> {
         .enumerate()
         .map(|(i, d)| i as u64 * d.unwrap() as u64)
         .sum();
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
 fn pop_next_set(queue: &mut VecDeque<u32>) -> Vec<Option<u32>> {
     let mut next_set = Vec::from([queue.pop_front()]);
     loop {
-        if next_set.contains(&queue.get(0).cloned()) {
+        if next_set.contains(&queue.front().cloned()) {
             next_set.push(queue.pop_front());
         } else {
             break;
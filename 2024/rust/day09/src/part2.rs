@@ -1,3 +1,5 @@
+use aoc_answer::Answer;
+
 #[derive(Debug, Clone, Copy)]
 struct Sector {
     name: u32,
@@ -12,7 +14,7 @@ enum SectorType {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
+pub fn process(input: &str) -> miette::Result<Answer> {
     let disk = input
         .trim()
         .chars()
@@ -91,7 +93,7 @@ pub fn process(input: &str) -> miette::Result<String> {
         acc
     });
 
-    Ok(checksum.1.to_string())
+    Ok(checksum.1.into())
 }
 
 #[cfg(test)]
@@ -1,69 +1,30 @@
-use std::collections::{HashMap, HashSet};
-
+use aoc_answer::Answer;
+use aoc_grid::Grid;
+use aoc_search::bfs_all;
 use glam::IVec2;
-use itertools::Itertools;
-
-const DIRECTIONS: [IVec2; 4] = [IVec2::NEG_X, IVec2::X, IVec2::NEG_Y, IVec2::Y];
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let topo = parse(input);
-    let total: usize = topo
-        .clone()
-        .into_iter()
-        .filter(|(_, v)| v == &0)
-        .map(|(k, _)| walk(k, &topo))
-        .sum();
-
-    Ok(total.to_string())
-}
-
-fn walk(loc: IVec2, topo: &HashMap<IVec2, u32>) -> usize {
-    let mut paths = HashSet::from([Vec::from([loc])]);
-
-    loop {
-        let new_paths = paths
-            .iter()
-            .cartesian_product(DIRECTIONS.iter())
-            .filter_map(|(path, dir)| {
-                let location = path.last().copied().unwrap();
-                let next_location = location + dir;
-
-                let curr_elev = topo.get(&location).unwrap();
-                let next_elev = topo.get(&next_location);
-
-                match (curr_elev, next_elev) {
-                    (9, _) => Some(path.clone()),
-                    (_, Some(e)) if *e == (curr_elev + 1) => {
-                        let mut new_path = path.clone();
-                        new_path.push(next_location);
-                        Some(new_path)
-                    }
-                    _ => None,
-                }
-            })
-            .collect::<HashSet<Vec<IVec2>>>();
-
-        if new_paths == paths {
-            break;
-        }
-        paths = new_paths.clone();
-    }
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let topo = Grid::parse_with(input, |c| c.to_digit(10))?;
+    let total: usize = topo.find_all(&0).map(|k| walk(k, &topo)).sum();
 
-    paths.len()
+    Ok(total.into())
 }
 
-fn parse(input: &str) -> HashMap<IVec2, u32> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(x, v)| (IVec2::new(x as i32, y as i32), v.to_digit(10).unwrap()))
-                .collect::<HashMap<IVec2, u32>>()
-        })
-        .collect::<HashMap<IVec2, u32>>()
+/// The number of distinct uphill trails from `trailhead` to a height 9.
+///
+/// Every step climbs by one, so all trails are shortest paths and the predecessor DAG holds them all.
+fn walk(trailhead: IVec2, topo: &Grid<u32>) -> usize {
+    bfs_all(
+        &trailhead,
+        |&loc| {
+            let next_elev = topo[loc] + 1;
+            topo.neighbours4(loc)
+                .filter(move |&next| topo[next] == next_elev)
+        },
+        |&loc| topo[loc] == 9,
+    )
+    .map_or(0, |trails| trails.count() as usize)
 }
 
 #[cfg(test)]
@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../../../input_day11.txt");
-    let result = process(file, 75).context("process part 2")?;
+    let file = aoc_input::load_cli(11)?;
+    let result = process(&file, 75).context("process part 2")?;
     println!("Part 2: {}", result);
     Ok(())
 }
@@ -1,14 +1,13 @@
-use miette::miette;
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use winnow::{
     ascii::{digit1, space1},
     combinator::separated,
-    Parser,
 };
 
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let mut stones = parse(input)
-        .map_err(|e| miette!("failed to parse {}", e))?
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let mut stones = parse(input)?
         .iter()
         .map(|s| s.to_string())
         .collect::<Vec<String>>();
@@ -31,16 +30,16 @@ pub fn process(input: &mut &str) -> miette::Result<String> {
                 _ => {
                     let d = stone.parse::<u64>().unwrap();
                     let new_d = d * 2024;
-                    Vec::from([String::from(format!("{new_d}"))])
+                    Vec::from([format!("{new_d}")])
                 }
             })
             .collect::<Vec<String>>();
     }
-    Ok(stones.len().to_string())
+    Ok(stones.len().into())
 }
 
-fn parse<'a>(input: &mut &'a str) -> winnow::PResult<Vec<&'a str>> {
-    separated(1.., digit1, space1).parse_next(input)
+fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    aoc_parse::parse(input, separated(1.., digit1, space1))
 }
 
 #[cfg(test)]
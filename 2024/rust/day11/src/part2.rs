@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
+use aoc_answer::Answer;
+
 #[tracing::instrument]
-pub fn process(input: &str, blinks: u32) -> miette::Result<String> {
+pub fn process(input: &str, blinks: u32) -> miette::Result<Answer> {
     let mut stones: HashMap<u64, u64> = HashMap::new();
     for stone in input
         .split_ascii_whitespace()
@@ -41,7 +43,7 @@ pub fn process(input: &str, blinks: u32) -> miette::Result<String> {
         stones = new_stones.clone();
     }
 
-    Ok(stones.values().sum::<u64>().to_string())
+    Ok(stones.values().sum::<u64>().into())
 }
 
 #[cfg(test)]
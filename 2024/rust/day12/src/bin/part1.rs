@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../../../input_day12.txt");
-    let result = process(file).context("process part 1")?;
+    let file = aoc_input::load_cli(12)?;
+    let result = process(&file).context("process part 1")?;
     println!("Part 1: {}", result);
     Ok(())
 }
@@ -1,14 +1,13 @@
-use std::{collections::HashMap, iter::successors};
+use std::iter::successors;
 
+use aoc_answer::Answer;
+use aoc_grid::{Direction, Grid};
 use glam::IVec2;
 use itertools::Itertools;
 
-//                                      North         East      South     West
-pub const DIRECTIONS: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
-
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let garden = parse(input);
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let garden = Grid::parse(input)?;
     let mut visited_plots = Vec::new();
     let mut total_price = 0;
 
@@ -22,15 +21,18 @@ pub fn process(input: &str) -> miette::Result<String> {
         let curr_plots = successors(Some(vec![plot]), |p| {
             let next_vec = p
                 .iter()
-                .cartesian_product(DIRECTIONS)
-                .filter_map(|(&p, dir)| match garden.get_key_value(&(p + dir)) {
-                    Some((next_plot, next_crop))
-                        if !visited_plots.contains(&next_plot) && next_crop == crop =>
-                    {
-                        visited_plots.push(next_plot);
-                        Some(next_plot)
+                .cartesian_product(Direction::CARDINAL)
+                .filter_map(|(&p, dir)| {
+                    let next_plot = p + dir;
+                    match garden.get(next_plot) {
+                        Some(next_crop)
+                            if !visited_plots.contains(&next_plot) && next_crop == crop =>
+                        {
+                            visited_plots.push(next_plot);
+                            Some(next_plot)
+                        }
+                        _ => None,
                     }
-                    _ => None,
                 })
                 .collect::<Vec<_>>();
 
@@ -41,36 +43,22 @@ pub fn process(input: &str) -> miette::Result<String> {
             }
         })
         .flatten()
-        .collect::<Vec<&IVec2>>();
+        .collect::<Vec<IVec2>>();
 
         let area = curr_plots.len();
-        let edges = DIRECTIONS
+        let edges = Direction::CARDINAL
             .iter()
             .cartesian_product(&curr_plots)
-            .filter(|&(dir1, &curr_plot)| {
-                let d1 = dir1 + curr_plot;
-                !curr_plots.contains(&&d1)
+            .filter(|&(&dir1, &curr_plot)| {
+                let d1 = curr_plot + dir1;
+                !curr_plots.contains(&d1)
             })
             .count();
 
         total_price += area * edges;
     }
 
-    Ok(total_price.to_string())
-}
-
-fn parse(input: &str) -> HashMap<IVec2, char> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(x, c)| (IVec2::new(x as i32, y as i32), c))
-                .collect_vec()
-        })
-        // .filter(|(_, crop)| *crop == 'E')
-        .collect::<HashMap<IVec2, char>>()
+    Ok(total_price.into())
 }
 
 // Test Case:
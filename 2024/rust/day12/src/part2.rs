@@ -1,14 +1,13 @@
-use std::{collections::HashMap, iter::successors};
+use std::iter::successors;
 
+use aoc_answer::Answer;
+use aoc_grid::{Direction, Grid};
 use glam::IVec2;
 use itertools::Itertools;
 
-//                                      North         East      South     West
-pub const DIRECTIONS: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
-
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let garden = parse(input);
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let garden = Grid::parse(input)?;
     let mut visited_plots = vec![];
     let mut total = 0;
 
@@ -22,15 +21,18 @@ pub fn process(input: &str) -> miette::Result<String> {
         let curr_plots = successors(Some(vec![plot]), |p| {
             let next_vec = p
                 .iter()
-                .cartesian_product(DIRECTIONS)
-                .filter_map(|(&p, dir)| match garden.get_key_value(&(p + dir)) {
-                    Some((next_plot, next_crop))
-                        if !visited_plots.contains(&next_plot) && next_crop == crop =>
-                    {
-                        visited_plots.push(next_plot);
-                        Some(next_plot)
+                .cartesian_product(Direction::CARDINAL)
+                .filter_map(|(&p, dir)| {
+                    let next_plot = p + dir;
+                    match garden.get(next_plot) {
+                        Some(next_crop)
+                            if !visited_plots.contains(&next_plot) && next_crop == crop =>
+                        {
+                            visited_plots.push(next_plot);
+                            Some(next_plot)
+                        }
+                        _ => None,
                     }
-                    _ => None,
                 })
                 .collect::<Vec<_>>();
 
@@ -41,22 +43,22 @@ pub fn process(input: &str) -> miette::Result<String> {
             }
         })
         .flatten()
-        .collect::<Vec<&IVec2>>();
+        .collect::<Vec<IVec2>>();
 
         let area = curr_plots.len();
-        let corners = DIRECTIONS
+        // Each plot has a corner between a direction and the next one clockwise.
+        let corners = Direction::CARDINAL
             .iter()
-            .circular_tuple_windows()
-            .take(4)
             .cartesian_product(&curr_plots)
-            .filter_map(|((dir1, dir2), &curr_plot)| {
-                let d1 = dir1 + curr_plot;
-                let diag = dir1 + dir2 + curr_plot;
-                let d2 = dir2 + curr_plot;
+            .filter_map(|(&dir1, &curr_plot)| {
+                let dir2 = dir1.turn_right();
+                let d1 = curr_plot + dir1;
+                let diag = curr_plot + dir1 + dir2;
+                let d2 = curr_plot + dir2;
                 match (
-                    curr_plots.contains(&&d1),
-                    curr_plots.contains(&&diag),
-                    curr_plots.contains(&&d2),
+                    curr_plots.contains(&d1),
+                    curr_plots.contains(&diag),
+                    curr_plots.contains(&d2),
                 ) {
                     (false, false, false) => Some(curr_plot),
                     (true, false, true) => Some(curr_plot),
@@ -69,21 +71,7 @@ pub fn process(input: &str) -> miette::Result<String> {
         total += area * corners;
     }
 
-    Ok(total.to_string())
-}
-
-fn parse(input: &str) -> HashMap<IVec2, char> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(x, c)| (IVec2::new(x as i32, y as i32), c))
-                .collect_vec()
-        })
-        // .filter(|(_, crop)| *crop == 'E')
-        .collect::<HashMap<IVec2, char>>()
+    Ok(total.into())
 }
 
 // The larger example from before now has the following updated prices:
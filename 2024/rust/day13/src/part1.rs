@@ -1,4 +1,3 @@
-use miette::miette;
 use num_traits::ops::euclid::Euclid;
 use std::fmt::Debug;
 use winnow::{
@@ -7,17 +6,19 @@ use winnow::{
     prelude::*,
 };
 
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use glam::IVec2;
 
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let games = parse(input).map_err(|_| miette!("should be able to parse AoC input"))?;
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let games = parse(input)?;
     let tokens = games
         .iter()
-        .filter_map(|g| solve(g))
-        .map(|(a, b)| a * 3 + b * 1)
+        .filter_map(solve)
+        .map(|(a, b)| a * 3 + b)
         .sum::<u32>();
-    Ok(tokens.to_string())
+    Ok(tokens.into())
 }
 
 fn solve(game: &Game) -> Option<(u32, u32)> {
@@ -74,8 +75,11 @@ fn parse_game(input: &mut &str) -> PResult<Game> {
         .parse_next(input)
 }
 
-fn parse(input: &mut &str) -> PResult<Vec<Game>> {
-    separated(1.., parse_game, (line_ending, line_ending)).parse_next(input)
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    aoc_parse::parse(
+        input,
+        separated(1.., parse_game, (line_ending, line_ending)),
+    )
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -112,7 +116,7 @@ Prize: X=18641, Y=10279";
 
     #[test]
     fn test_parse_game() -> miette::Result<()> {
-        let mut input = "Button A: X+17, Y+86
+        let input = "Button A: X+17, Y+86
 Button B: X+84, Y+37
 Prize: X=7870, Y=6450";
         let expected = Game {
@@ -120,9 +124,7 @@ Prize: X=7870, Y=6450";
             b: IVec2::new(84, 37),
             prize: IVec2::new(7870, 6450),
         };
-        let observed = parse_game
-            .parse_next(&mut input)
-            .map_err(|e| miette!("should parse {}", e))?;
+        let observed = aoc_parse::parse(input, parse_game)?;
         assert_eq!(expected, observed);
         Ok(())
     }
@@ -1,4 +1,3 @@
-use miette::miette;
 use num_traits::ops::euclid::Euclid;
 use std::fmt::Debug;
 use winnow::{
@@ -7,27 +6,36 @@ use winnow::{
     prelude::*,
 };
 
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use glam::{I64Vec2, IVec2};
 
+/// Part 2 prizes are this much further away on both axes than the input says.
+const PRIZE_OFFSET: i64 = 10_000_000_000_000;
+
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let games = parse(input).map_err(|_| miette!("should be able to parse AoC input"))?;
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let games = parse(input)?;
     let tokens = games
-        .iter()
-        .filter_map(|g| solve(g))
-        .map(|(a, b)| a * 3 + b * 1)
+        .into_iter()
+        .map(|game| Game {
+            prize: game.prize + I64Vec2::splat(PRIZE_OFFSET),
+            ..game
+        })
+        .filter_map(|game| solve(&game))
+        .map(|(a, b)| a * 3 + b)
         .sum::<u64>();
-    Ok(tokens.to_string())
+    Ok(tokens.into())
 }
 
 fn solve(game: &Game) -> Option<(u64, u64)> {
     let (a_div, a_rem) = Euclid::div_rem_euclid(
-        &(game.b.x as i64 * game.prize.y as i64 - game.b.y as i64 * game.prize.x as i64),
+        &(game.b.x as i64 * game.prize.y - game.b.y as i64 * game.prize.x),
         &(game.b.x as i64 * game.a.y as i64 - game.b.y as i64 * game.a.x as i64),
     );
 
     let (b_div, b_rem) = Euclid::div_rem_euclid(
-        &(game.a.x as i64 * game.prize.y as i64 - game.a.y as i64 * game.prize.x as i64),
+        &(game.a.x as i64 * game.prize.y - game.a.y as i64 * game.prize.x),
         &(game.a.x as i64 * game.b.y as i64 - game.a.y as i64 * game.b.x as i64),
     );
 
@@ -60,7 +68,7 @@ fn parse_prize(input: &mut &str) -> PResult<I64Vec2> {
         "Prize: X=",
         separated_pair(dec_int::<_, i64, _>, ", Y=", dec_int::<_, i64, _>),
     )
-    .map(|(x, y)| I64Vec2::new(x + 10000000000000, y + 10000000000000))
+    .map(|(x, y)| I64Vec2::new(x, y))
     .parse_next(input)
 }
 
@@ -74,8 +82,11 @@ fn parse_game(input: &mut &str) -> PResult<Game> {
         .parse_next(input)
 }
 
-fn parse(input: &mut &str) -> PResult<Vec<Game>> {
-    separated(1.., parse_game, (line_ending, line_ending)).parse_next(input)
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    aoc_parse::parse(
+        input,
+        separated(1.., parse_game, (line_ending, line_ending)),
+    )
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -106,13 +117,13 @@ Prize: X=7870, Y=6450
 Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279";
-        assert_eq!("480", process(&mut input)?);
+        assert_eq!("875318608908", process(&mut input)?);
         Ok(())
     }
 
     #[test]
     fn test_parse_game() -> miette::Result<()> {
-        let mut input = "Button A: X+17, Y+86
+        let input = "Button A: X+17, Y+86
 Button B: X+84, Y+37
 Prize: X=7870, Y=6450";
         let expected = Game {
@@ -120,9 +131,7 @@ Prize: X=7870, Y=6450";
             b: IVec2::new(84, 37),
             prize: I64Vec2::new(7870, 6450),
         };
-        let observed = parse_game
-            .parse_next(&mut input)
-            .map_err(|e| miette!("should parse {}", e))?;
+        let observed = aoc_parse::parse(input, parse_game)?;
         assert_eq!(expected, observed);
         Ok(())
     }
@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let mut file = include_str!("../../../../input_day14.txt");
-    let result = process(&mut file, 101, 103).context("process part 1")?;
+    let file = aoc_input::load_cli(14)?;
+    let result = process(&mut file.as_str(), 101, 103).context("process part 1")?;
     println!("Part 1: {}", result);
     Ok(())
 }
@@ -1,12 +1,48 @@
-use day14::part2::process;
+use std::{path::PathBuf, time::Duration};
+
+use aoc_input::Source;
+use aoc_record::{Options, Recorder};
+use clap::Parser;
+use day14::part2::{process, process_with, MAP_SIZE};
 use miette::Context;
 
+#[derive(Debug, Parser)]
+#[command(name = "part2")]
+struct Cli {
+    /// Read the robots from this file (or `-` for stdin) instead of `input_day14.txt`.
+    input: Option<PathBuf>,
+    /// Record every round up to the tree as a `.gif` or asciinema `.cast`.
+    #[arg(long)]
+    record: Option<PathBuf>,
+}
+
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
+    let cli = Cli::parse();
+
+    let source = match cli.input {
+        Some(path) => Source::from_arg(path),
+        None => Source::for_day(14),
+    };
+    let file = source.read()?;
 
-    let mut file = include_str!("../../../../input_day14.txt");
-    let result = process(&mut file).context("process part 2")?;
+    let result = match &cli.record {
+        None => process(&mut file.as_str()).context("process part 2")?,
+        Some(path) => {
+            // Thousands of rounds go by before the tree, so keep the frames short.
+            let options = Options {
+                scale: 2,
+                delay: Duration::from_millis(20),
+            };
+            let mut recorder = Recorder::create(path, MAP_SIZE, options)?;
+            let result = process_with(&file, |frame| Ok(recorder.record(frame)?))
+                .context("process part 2")?;
+            tracing::info!(frames = recorder.frames(), "recorded {}", path.display());
+            recorder.finish()?;
+            result
+        }
+    };
     println!("Part 2: {}", result);
     Ok(())
 }
@@ -1,7 +1,8 @@
 use std::collections::BTreeMap;
 
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use glam::IVec2;
-use miette::miette;
 use winnow::{
     ascii::{dec_int, line_ending, space1},
     combinator::{preceded, separated, separated_pair},
@@ -19,11 +20,11 @@ enum Quadrant {
 }
 
 #[tracing::instrument]
-pub fn process(input: &mut &str, x_max: i32, y_max: i32) -> miette::Result<String> {
-    let robots = parse(input).map_err(|e| miette!("could not parse {}", e))?;
+pub fn process(input: &mut &str, x_max: i32, y_max: i32) -> miette::Result<Answer> {
+    let robots = parse(input)?;
     let score = robots
         .iter()
-        .map(|r| simulate(&r, x_max, y_max, SIM_SEC))
+        .map(|r| simulate(r, x_max, y_max, SIM_SEC))
         .filter_map(|l| assign_quadrant(l, x_max, y_max))
         .fold(BTreeMap::new(), |mut acc, q| {
             acc.entry(q).and_modify(|v| *v += 1).or_insert(1);
@@ -33,7 +34,7 @@ pub fn process(input: &mut &str, x_max: i32, y_max: i32) -> miette::Result<Strin
         .map(|m| m.1)
         .product::<u64>();
 
-    Ok(score.to_string())
+    Ok(score.into())
 }
 
 fn assign_quadrant(loc: IVec2, x_max: i32, y_max: i32) -> Option<Quadrant> {
@@ -77,21 +78,25 @@ struct Robot {
     velocity: IVec2,
 }
 
-fn parse(input: &mut &str) -> PResult<Vec<Robot>> {
-    separated(
-        1..,
-        separated_pair(
-            preceded("p=", separated_pair(dec_int, ",", dec_int)).map(|(x, y)| IVec2::new(x, y)),
-            space1,
-            preceded("v=", separated_pair(dec_int, ",", dec_int)).map(|(x, y)| IVec2::new(x, y)),
-        )
-        .map(|(p, v)| Robot {
-            position: p,
-            velocity: v,
-        }),
-        line_ending,
+fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    aoc_parse::parse(
+        input,
+        separated(
+            1..,
+            separated_pair(
+                preceded("p=", separated_pair(dec_int, ",", dec_int))
+                    .map(|(x, y)| IVec2::new(x, y)),
+                space1,
+                preceded("v=", separated_pair(dec_int, ",", dec_int))
+                    .map(|(x, y)| IVec2::new(x, y)),
+            )
+            .map(|(p, v)| Robot {
+                position: p,
+                velocity: v,
+            }),
+            line_ending,
+        ),
     )
-    .parse_next(input)
 }
 
 #[cfg(test)]
@@ -1,68 +1,131 @@
 use std::collections::HashMap;
 
+use aoc_answer::Answer;
+use aoc_grid::Grid;
+use aoc_parse::ParseError;
 use glam::IVec2;
-use itertools::Itertools;
-use miette::miette;
 use winnow::{
     ascii::{dec_int, line_ending, space1},
     combinator::{preceded, separated, separated_pair},
     prelude::*,
 };
 
-const MAP_SIZE: IVec2 = IVec2::new(101, 103);
+pub const MAP_SIZE: IVec2 = IVec2::new(101, 103);
+
+/// Give up looking for the tree after this many rounds.
+const MAX_ROUNDS: i32 = 10000;
 
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let robots = parse(input).map_err(|e| miette!("could not parse {}", e))?;
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    process_with(input, |_| Ok(()))
+}
+
+/// [`process`], handing `watch` the field of every round up to and including the first tree,
+/// e.g. to record it.
+pub fn process_with(
+    input: &str,
+    mut watch: impl FnMut(&Grid<char>) -> miette::Result<()>,
+) -> miette::Result<Answer> {
+    let robots = parse(input)?;
     let mut plots = Vec::new();
-    for i in 1..10000 {
-        let field = robots.iter().fold(HashMap::new(), |mut acc, r| {
-            let loc = (r.position + r.velocity * i).rem_euclid(MAP_SIZE);
-            acc.entry(loc).and_modify(|v| *v += 1).or_insert(1);
-            acc
-        });
-        if tree_test(&field) {
-            plots.push(format!("Round {}\n{}\n\n", i, print_field(field)));
+    let mut watching = true;
+    for (i, field) in rounds(&robots) {
+        let tree = tree_test(&field);
+        if watching {
+            watch(&frame(&field))?;
+            watching = !tree;
+        }
+        if tree {
+            plots.push(format!("Round {}\n{}\n\n", i, frame(&field)));
         }
     }
-    Ok(plots.into_iter().collect::<String>())
+    Ok(Answer::art(plots.into_iter().collect::<String>()))
+}
+
+/// Every round from the first second on, with how many robots stand on each tile.
+pub fn rounds(robots: &[Robot]) -> impl Iterator<Item = (i32, HashMap<IVec2, i32>)> + '_ {
+    (1..MAX_ROUNDS).map(|i| (i, positions(robots, i)))
+}
+
+/// How many robots stand on each tile after `round` seconds.
+fn positions(robots: &[Robot], round: i32) -> HashMap<IVec2, i32> {
+    robots.iter().fold(HashMap::new(), |mut acc, r| {
+        let loc = (r.position + r.velocity * round).rem_euclid(MAP_SIZE);
+        acc.entry(loc).and_modify(|v| *v += 1).or_insert(1);
+        acc
+    })
 }
 
-fn tree_test(field: &HashMap<IVec2, i32>) -> bool {
+pub fn tree_test(field: &HashMap<IVec2, i32>) -> bool {
     field.values().all(|v| *v == 1)
 }
-fn print_field(field: HashMap<IVec2, i32>) -> String {
-    (0..MAP_SIZE.x)
-        .map(|x| {
-            (0..MAP_SIZE.y)
-                .map(|y| match field.get(&IVec2::new(x, y)) {
-                    None => ".".to_string(),
-                    Some(cnt) => format!("{cnt}"),
-                })
-                .collect::<String>()
-        })
-        .join("\n")
+
+/// The field with robot counts above 9 drawn as `9`.
+pub fn frame(field: &HashMap<IVec2, i32>) -> Grid<char> {
+    let mut grid = Grid::new(MAP_SIZE.x, MAP_SIZE.y, '.');
+    for (pos, cnt) in field {
+        grid[*pos] = char::from_digit((*cnt).min(9) as u32, 10).unwrap_or('9');
+    }
+    grid
 }
 
 #[derive(Debug)]
-struct Robot {
+pub struct Robot {
     position: IVec2,
     velocity: IVec2,
 }
 
-fn parse(input: &mut &str) -> PResult<Vec<Robot>> {
-    separated(
-        1..,
-        separated_pair(
-            preceded("p=", separated_pair(dec_int, ",", dec_int)).map(|(x, y)| IVec2::new(x, y)),
-            space1,
-            preceded("v=", separated_pair(dec_int, ",", dec_int)).map(|(x, y)| IVec2::new(x, y)),
-        )
-        .map(|(p, v)| Robot {
-            position: p,
-            velocity: v,
-        }),
-        line_ending,
+pub fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    aoc_parse::parse(
+        input,
+        separated(
+            1..,
+            separated_pair(
+                preceded("p=", separated_pair(dec_int, ",", dec_int))
+                    .map(|(x, y)| IVec2::new(x, y)),
+                space1,
+                preceded("v=", separated_pair(dec_int, ",", dec_int))
+                    .map(|(x, y)| IVec2::new(x, y)),
+            )
+            .map(|(p, v)| Robot {
+                position: p,
+                velocity: v,
+            }),
+            line_ending,
+        ),
     )
-    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() -> miette::Result<()> {
+        let robots = parse("p=2,4 v=2,-3\np=0,0 v=1,1\np=1,1 v=0,0")?;
+        let (round, field) = rounds(&robots).next().expect("there is a first round");
+        assert_eq!(1, round);
+        assert!(!tree_test(&field));
+        let grid = frame(&field);
+        assert_eq!(MAP_SIZE, grid.size());
+        assert_eq!('2', grid[IVec2::new(1, 1)]);
+        assert_eq!('1', grid[IVec2::new(4, 1)]);
+        assert_eq!(2, grid.iter().filter(|(_, c)| **c != '.').count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_with() -> miette::Result<()> {
+        // The moving robot lands on the other two after one and two seconds.
+        let input = "p=0,0 v=1,0\np=1,0 v=0,0\np=2,0 v=0,0";
+        let mut watched = vec![];
+        let answer = process_with(input, |grid| {
+            watched.push(grid.clone());
+            Ok(())
+        })?;
+        assert_eq!(3, watched.len());
+        assert_eq!('2', watched[0][IVec2::new(1, 0)]);
+        assert!(answer.to_string().starts_with("Round 3\n"));
+        Ok(())
+    }
 }
@@ -1,12 +1,43 @@
-use day15::part2::process;
+use std::path::PathBuf;
+
+use aoc_answer::Answer;
+use aoc_input::Source;
+use clap::Parser;
+use day15::{
+    part2::process,
+    warehouse::{record, Warehouse},
+};
 use miette::Context;
 
+#[derive(Debug, Parser)]
+#[command(name = "part2")]
+struct Cli {
+    /// Read the warehouse from this file (or `-` for stdin) instead of `input_day15.txt`.
+    input: Option<PathBuf>,
+    /// Record the warehouse after every move as a `.gif` or asciinema `.cast`.
+    #[arg(long)]
+    record: Option<PathBuf>,
+}
+
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
+    let cli = Cli::parse();
+
+    let source = match cli.input {
+        Some(path) => Source::from_arg(path),
+        None => Source::for_day(15),
+    };
+    let file = source.read()?;
 
-    let mut file = include_str!("../../../../input_day15.txt");
-    let result = process(&mut file).context("process part 2")?;
+    let result = match &cli.record {
+        None => process(&mut file.as_str()).context("process part 2")?,
+        Some(path) => {
+            let (mut warehouse, moves) = Warehouse::parse_wide(&file)?;
+            record(&mut warehouse, moves, path)?;
+            Answer::from(warehouse.gps_sum())
+        }
+    };
     println!("Part 2: {}", result);
     Ok(())
 }
@@ -0,0 +1,343 @@
+use std::{collections::HashSet, fmt, io::Write, path::Path};
+
+use aoc_grid::{Direction, Grid};
+use aoc_record::{Options, RecordError, Recorder};
+use glam::IVec2;
+use miette::miette;
+
+/// The lanternfish warehouse: walls `#`, boxes `O` or wide boxes `[]`, and the robot `@`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warehouse {
+    grid: Grid<char>,
+    robot: IVec2,
+}
+
+impl Warehouse {
+    pub fn new(grid: Grid<char>) -> miette::Result<Self> {
+        if let Some((pos, c)) = grid.iter().find(|(_, c)| !"#.O[]@".contains(**c)) {
+            return Err(miette!("unexpected {c:?} at {pos} in the warehouse map"));
+        }
+        if let Some((pos, _)) = grid.iter().find(|&(pos, c)| match c {
+            '[' => grid.get(pos + Direction::East) != Some(&']'),
+            ']' => grid.get(pos + Direction::West) != Some(&'['),
+            _ => false,
+        }) {
+            return Err(miette!("wide box at {pos} is missing its other half"));
+        }
+        let robots = grid.find_all(&'@').collect::<Vec<_>>();
+        let robot = match robots[..] {
+            [robot] => robot,
+            [] => return Err(miette!("warehouse should have a robot")),
+            _ => {
+                return Err(miette!(
+                    "warehouse should have one robot, found {}",
+                    robots.len()
+                ))
+            }
+        };
+        Ok(Self { grid, robot })
+    }
+
+    /// The warehouse map and the robot's moves, separated by a blank line.
+    pub fn parse(input: &str) -> miette::Result<(Self, Vec<Direction>)> {
+        let (map, moves) = split(input)?;
+        Ok((Self::new(Grid::parse(map)?)?, parse_directions(moves)?))
+    }
+
+    /// Like [`Warehouse::parse`], with everything except the robot twice as wide.
+    pub fn parse_wide(input: &str) -> miette::Result<(Self, Vec<Direction>)> {
+        let (map, moves) = split(input)?;
+        Ok((Self::new(widen(map)?)?, parse_directions(moves)?))
+    }
+
+    pub fn robot(&self) -> IVec2 {
+        self.robot
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Try to move the robot, pushing any boxes in the way. Nothing moves if that would push
+    /// something into a wall.
+    pub fn step(&mut self, dir: Direction) -> bool {
+        let Some(to_move) = self.pushed(dir) else {
+            return false;
+        };
+
+        let moved = to_move
+            .iter()
+            .map(|&pos| (pos, self.grid[pos]))
+            .collect::<Vec<_>>();
+        for &(pos, _) in &moved {
+            self.grid[pos] = '.';
+        }
+        for (pos, c) in moved {
+            self.grid[pos + dir] = c;
+        }
+        self.robot += dir;
+        true
+    }
+
+    pub fn run(&mut self, moves: impl IntoIterator<Item = Direction>) {
+        for dir in moves {
+            self.step(dir);
+        }
+    }
+
+    /// [`Warehouse::run`], recording the map before the first move and after every move the
+    /// robot makes.
+    pub fn run_recorded<W: Write>(
+        &mut self,
+        moves: impl IntoIterator<Item = Direction>,
+        recorder: &mut Recorder<W>,
+    ) -> Result<(), RecordError> {
+        recorder.record(&self.grid)?;
+        for dir in moves {
+            if self.step(dir) {
+                recorder.record(&self.grid)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The sum of every box's GPS coordinate, measured from its left edge.
+    pub fn gps_sum(&self) -> i32 {
+        self.grid
+            .iter()
+            .filter(|(_, c)| matches!(c, 'O' | '['))
+            .map(|(pos, _)| 100 * pos.y + pos.x)
+            .sum()
+    }
+
+    /// The robot and every box it would push, found breadth first from the robot. A wide box
+    /// is always taken whole, so pushing one half up or down brings the other half along and
+    /// that half can push more boxes in turn. The edge of the map stops boxes like a wall.
+    fn pushed(&self, dir: Direction) -> Option<Vec<IVec2>> {
+        let mut to_move = vec![self.robot];
+        let mut seen = HashSet::from([self.robot]);
+        let mut i = 0;
+        while let Some(&pos) = to_move.get(i) {
+            i += 1;
+            let next = pos + dir;
+            let cells = match self.grid.get(next) {
+                None | Some('#') => return None,
+                Some('.') => continue,
+                Some('[') => vec![next, next + Direction::East],
+                Some(']') => vec![next, next + Direction::West],
+                Some(_) => vec![next],
+            };
+            for cell in cells {
+                if seen.insert(cell) {
+                    to_move.push(cell);
+                }
+            }
+        }
+        Some(to_move)
+    }
+}
+
+impl fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+/// Run `moves`, recording the warehouse to `path` as a `.gif` or asciinema `.cast`.
+pub fn record(
+    warehouse: &mut Warehouse,
+    moves: impl IntoIterator<Item = Direction>,
+    path: &Path,
+) -> Result<(), RecordError> {
+    let mut recorder = Recorder::create(path, warehouse.grid.size(), Options::default())?;
+    warehouse.run_recorded(moves, &mut recorder)?;
+    recorder.finish()?;
+    Ok(())
+}
+
+fn split(input: &str) -> miette::Result<(&str, &str)> {
+    input.split_once("\n\n").ok_or(miette!(
+        "expected the map and the moves separated by a blank line"
+    ))
+}
+
+/// Double the width of every tile, so boxes become `[]` and the robot keeps its left half.
+fn widen(map: &str) -> miette::Result<Grid<char>> {
+    let wide = map
+        .lines()
+        .map(|l| {
+            l.chars()
+                .map(|c| match c {
+                    '#' => Ok("##"),
+                    '.' => Ok(".."),
+                    'O' => Ok("[]"),
+                    '@' => Ok("@."),
+                    _ => Err(miette!("unexpected {c:?} in the warehouse map")),
+                })
+                .collect::<miette::Result<String>>()
+        })
+        .collect::<miette::Result<Vec<String>>>()?
+        .join("\n");
+
+    Ok(Grid::parse(&wide)?)
+}
+
+fn parse_directions(input: &str) -> miette::Result<Vec<Direction>> {
+    input
+        .lines()
+        .flat_map(|l| l.chars().map(Direction::try_from))
+        .collect::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const SMALL: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let (warehouse, moves) = Warehouse::parse(SMALL)?;
+        assert_eq!(IVec2::new(2, 2), warehouse.robot());
+        assert_eq!('#', warehouse.grid()[IVec2::new(0, 0)]);
+        assert_eq!('O', warehouse.grid()[IVec2::new(3, 1)]);
+        assert_eq!(15, moves.len());
+        assert_eq!(Direction::West, moves[0]);
+        assert_eq!(Direction::North, moves[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_wide() -> miette::Result<()> {
+        let (warehouse, _) = Warehouse::parse_wide(SMALL)?;
+        assert_eq!(IVec2::new(4, 2), warehouse.robot());
+        assert_eq!(IVec2::new(16, 8), warehouse.grid().size());
+        assert_eq!(
+            "##....[]..[]..##",
+            warehouse.to_string().lines().nth(1).unwrap()
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("#x@\n\n<", "unexpected 'x' at [1, 0] in the warehouse map")]
+    #[case("#..\n\n<", "warehouse should have a robot")]
+    #[case("#.@", "expected the map and the moves separated by a blank line")]
+    #[case("@.@\n\n<", "warehouse should have one robot, found 2")]
+    #[case("@[.\n\n<", "wide box at [1, 0] is missing its other half")]
+    fn test_parse_errors(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(expected, Warehouse::parse(input).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_step() -> miette::Result<()> {
+        let (mut warehouse, _) = Warehouse::parse(SMALL)?;
+        assert!(!warehouse.step(Direction::West));
+        assert!(warehouse.step(Direction::North));
+        assert!(!warehouse.step(Direction::North));
+        assert!(warehouse.step(Direction::East));
+        assert!(warehouse.step(Direction::East));
+        assert_eq!("#...@OO#", warehouse.to_string().lines().nth(1).unwrap());
+        // Two boxes are now up against the wall.
+        assert!(!warehouse.step(Direction::East));
+        assert_eq!(IVec2::new(4, 1), warehouse.robot());
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide_push() -> miette::Result<()> {
+        let (mut warehouse, moves) = Warehouse::parse_wide(
+            "#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^",
+        )?;
+        warehouse.run(moves);
+        assert_eq!(
+            "##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############",
+            warehouse.to_string()
+        );
+        assert_eq!(105 + 207 + 306, warehouse.gps_sum());
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide_push_blocked_by_other_half() -> miette::Result<()> {
+        let mut warehouse = Warehouse::new(Grid::parse(
+            "#######
+##.#..#
+#.[]..#
+#.@...#
+#######",
+        )?)?;
+        // The left half of the box has room above it, but the right half is under a wall.
+        let before = warehouse.clone();
+        assert!(!warehouse.step(Direction::North));
+        assert_eq!(before, warehouse);
+
+        assert!(warehouse.step(Direction::East));
+        assert!(warehouse.step(Direction::East));
+        assert!(warehouse.step(Direction::North));
+        assert_eq!(IVec2::new(4, 2), warehouse.robot());
+        assert_eq!("#.[]@.#", warehouse.to_string().lines().nth(2).unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_off_edge() -> miette::Result<()> {
+        // Without a wall border the edge of the map is all that stops the robot and the boxes.
+        let mut warehouse = Warehouse::new(Grid::parse(".@O\n[].")?)?;
+        assert!(!warehouse.step(Direction::East));
+        assert!(!warehouse.step(Direction::North));
+        assert!(warehouse.step(Direction::West));
+        assert!(!warehouse.step(Direction::West));
+        assert!(!warehouse.step(Direction::South));
+        assert_eq!("@.O\n[].", warehouse.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_recorded() -> miette::Result<()> {
+        let (mut warehouse, moves) = Warehouse::parse(SMALL)?;
+        let mut recorder = Recorder::new(
+            vec![],
+            aoc_record::Format::Cast,
+            warehouse.grid().size(),
+            Options::default(),
+        )?;
+        warehouse.run_recorded(moves.clone(), &mut recorder)?;
+
+        // The start, then one frame per move that is not blocked.
+        let (mut unrecorded, _) = Warehouse::parse(SMALL)?;
+        let made = moves
+            .into_iter()
+            .filter(|&dir| unrecorded.step(dir))
+            .count();
+        assert_eq!(1 + made, recorder.frames());
+        assert!(made < 15);
+        assert_eq!(unrecorded, warehouse);
+        Ok(())
+    }
+}
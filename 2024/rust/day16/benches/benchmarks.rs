@@ -1,4 +1,9 @@
+use std::cmp;
+
+use aoc_grid::{Direction, Grid};
 use day16::*;
+use glam::IVec2;
+use itertools::Itertools;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,17 +11,63 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../../../input_day16.txt"
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_input::load(16).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../../../input_day16.txt"
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_input::load(16).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
+}
+
+/// The exhaustive search part 1 used before `ReindeerMaze`, kept as a baseline for the Dijkstra
+/// search that replaced it.
+#[divan::bench(sample_count = 3, sample_size = 1)]
+fn part1_brute_force(bencher: divan::Bencher) {
+    let input = aoc_input::load(16).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| brute_force(divan::black_box(&input)));
+}
+
+fn brute_force(input: &str) -> u32 {
+    let map = Grid::parse(input).unwrap();
+    let start = map.find(&'S').unwrap();
+
+    let mut best_score = u32::MAX;
+    let mut curr_paths = vec![(vec![start], Direction::East, 0)];
+
+    while !curr_paths.is_empty() {
+        curr_paths = Direction::CARDINAL
+            .iter()
+            .cartesian_product(curr_paths)
+            .filter_map(
+                |(&dir, (mut locs, facing, score)): (_, (Vec<IVec2>, _, u32))| {
+                    if dir == facing.turn_around() {
+                        return None;
+                    }
+
+                    let step = if dir == facing { 1 } else { 1001 };
+                    let next_loc = *locs.last().unwrap() + dir;
+                    if score + step >= best_score || locs.contains(&next_loc) {
+                        return None;
+                    }
+
+                    match map.get(next_loc) {
+                        Some('.') => {
+                            locs.push(next_loc);
+                            Some((locs, dir, score + step))
+                        }
+                        Some('E') => {
+                            best_score = cmp::min(best_score, score + step);
+                            None
+                        }
+                        _ => None,
+                    }
+                },
+            )
+            .collect();
+    }
+
+    best_score
 }
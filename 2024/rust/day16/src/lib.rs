@@ -1,3 +1,3 @@
+pub mod maze;
 pub mod part1;
-pub mod part1_path;
 pub mod part2;
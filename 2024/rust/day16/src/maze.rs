@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+use aoc_grid::{Direction, Grid};
+use aoc_search::{dijkstra, dijkstra_distances};
+use glam::IVec2;
+use miette::miette;
+
+// The Reindeer start on the Start Tile (marked S) facing East and need to reach the End Tile
+// (marked E). They can move forward one tile at a time (increasing their score by 1 point),
+// but never into a wall (#). They can also rotate clockwise or counterclockwise 90 degrees
+// at a time (increasing their score by 1000 points).
+
+/// Where a reindeer is and which way it faces.
+pub type Reindeer = (IVec2, Direction);
+
+/// The Reindeer Maze with what it costs to step forward and to turn.
+#[derive(Debug, Clone)]
+pub struct ReindeerMaze {
+    grid: Grid<char>,
+    start: IVec2,
+    end: IVec2,
+    step_cost: u32,
+    turn_cost: u32,
+}
+
+impl ReindeerMaze {
+    pub const STEP_COST: u32 = 1;
+    pub const TURN_COST: u32 = 1000;
+
+    pub fn parse(input: &str) -> miette::Result<Self> {
+        let grid = Grid::parse(input)?;
+        let start = grid.find(&'S').ok_or(miette!("maze should have a start"))?;
+        let end = grid.find(&'E').ok_or(miette!("maze should have an end"))?;
+        Ok(Self {
+            grid,
+            start,
+            end,
+            step_cost: Self::STEP_COST,
+            turn_cost: Self::TURN_COST,
+        })
+    }
+
+    pub fn with_costs(mut self, step_cost: u32, turn_cost: u32) -> Self {
+        self.step_cost = step_cost;
+        self.turn_cost = turn_cost;
+        self
+    }
+
+    pub fn start(&self) -> Reindeer {
+        (self.start, Direction::East)
+    }
+
+    pub fn end(&self) -> IVec2 {
+        self.end
+    }
+
+    fn is_wall(&self, pos: IVec2) -> bool {
+        self.grid.get(pos).is_none_or(|&c| c == '#')
+    }
+
+    /// Turning on the spot, or stepping forward when there is no wall ahead.
+    pub fn moves(&self, &(pos, dir): &Reindeer) -> Vec<(Reindeer, u32)> {
+        let mut next = vec![
+            ((pos, dir.turn_left()), self.turn_cost),
+            ((pos, dir.turn_right()), self.turn_cost),
+        ];
+        if !self.is_wall(pos + dir) {
+            next.push(((pos + dir, dir), self.step_cost));
+        }
+        next
+    }
+
+    /// The states [`ReindeerMaze::moves`] reaches `state` from.
+    fn reverse_moves(&self, &(pos, dir): &Reindeer) -> Vec<(Reindeer, u32)> {
+        let mut prev = vec![
+            ((pos, dir.turn_left()), self.turn_cost),
+            ((pos, dir.turn_right()), self.turn_cost),
+        ];
+        if !self.is_wall(pos - dir) {
+            prev.push(((pos - dir, dir), self.step_cost));
+        }
+        prev
+    }
+
+    /// The lowest score a reindeer can get.
+    pub fn best_score(&self) -> miette::Result<u32> {
+        let (_path, score) = dijkstra(
+            &self.start(),
+            |s| self.moves(s),
+            |&(pos, _)| pos == self.end,
+        )
+        .ok_or(miette!("maze should have a path to the end"))?;
+        Ok(score)
+    }
+
+    /// Every tile on at least one lowest-scoring path.
+    ///
+    /// The cheapest score from the start to a state plus the cheapest from that state to the end
+    /// is the best score exactly when the state lies on a best path, so two searches find them
+    /// all however many paths tie.
+    pub fn best_tiles(&self) -> miette::Result<HashSet<IVec2>> {
+        let forward = dijkstra_distances([self.start()], |s| self.moves(s));
+        // Walk backwards from the end, which may be reached facing any direction.
+        let backward = dijkstra_distances(Direction::CARDINAL.map(|dir| (self.end, dir)), |s| {
+            self.reverse_moves(s)
+        });
+
+        let best = Direction::CARDINAL
+            .iter()
+            .filter_map(|&dir| forward.get(&(self.end, dir)))
+            .min()
+            .ok_or(miette!("maze should have a path to the end"))?;
+        Ok(forward
+            .iter()
+            .filter(|&(state, score)| {
+                backward
+                    .get(state)
+                    .is_some_and(|rest| score + rest == *best)
+            })
+            .map(|((pos, _), _)| *pos)
+            .collect())
+    }
+
+    /// The maze with every tile in `tiles` drawn as `O`.
+    pub fn render(&self, tiles: &HashSet<IVec2>) -> String {
+        let mut overlay = self.grid.clone();
+        for &tile in tiles {
+            overlay[tile] = 'O';
+        }
+        overlay.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MAZE: &str = "#######
+#....E#
+#.##.##
+#S...##
+#######";
+
+    #[rstest]
+    #[case(ReindeerMaze::STEP_COST, ReindeerMaze::TURN_COST, 2006)]
+    #[case(1, 0, 6)]
+    #[case(10, 1, 62)]
+    fn test_costs(
+        #[case] step_cost: u32,
+        #[case] turn_cost: u32,
+        #[case] expected: u32,
+    ) -> miette::Result<()> {
+        let maze = ReindeerMaze::parse(MAZE)?.with_costs(step_cost, turn_cost);
+        assert_eq!(expected, maze.best_score()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_best_tiles() -> miette::Result<()> {
+        let maze = ReindeerMaze::parse(MAZE)?;
+        // Both ways round the pillar take six steps and two turns.
+        assert_eq!(
+            "#######
+#OOOOO#
+#O##O##
+#OOOO##
+#######",
+            maze.render(&maze.best_tiles()?)
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("###\n#E#\n###", "maze should have a start")]
+    #[case("###\n#S#\n###", "maze should have an end")]
+    #[case("####\n#S#E\n####", "maze should have a path to the end")]
+    fn test_errors(#[case] input: &str, #[case] expected: &str) -> miette::Result<()> {
+        let err = ReindeerMaze::parse(input).and_then(|maze| maze.best_score());
+        assert_eq!(expected, err.unwrap_err().to_string());
+        Ok(())
+    }
+}
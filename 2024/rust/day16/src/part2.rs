@@ -1,80 +1,15 @@
-use std::collections::HashSet;
+use aoc_answer::Answer;
 
-use glam::IVec2;
-use itertools::Itertools;
-use pathfinding::prelude::yen;
+use crate::maze::ReindeerMaze;
 
+/// How many tiles are part of at least one of the best paths through the maze?
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let (start, end, walls) = parse(input);
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let maze = ReindeerMaze::parse(input)?;
+    let tiles = maze.best_tiles()?;
+    tracing::debug!("tiles on a best path:\n{}", maze.render(&tiles));
 
-    let paths = yen(
-        &(start, IVec2::X),
-        |(pos, dir)| {
-            let next_pos = pos + dir;
-            if walls.contains(&next_pos) {
-                vec![((*pos, dir.perp()), 1000), ((*pos, -dir.perp()), 1000)]
-            } else {
-                vec![
-                    ((next_pos, *dir), 1),
-                    ((*pos, dir.perp()), 1000),
-                    ((*pos, -dir.perp()), 1000),
-                ]
-            }
-        },
-        |&(pos, _)| pos == end,
-        10,
-    );
-
-    let min_score = paths
-        .iter()
-        .map(|(_p, s)| s)
-        .min()
-        .expect("at least 1 path");
-
-    let n_spaces = paths
-        .iter()
-        .filter_map(|(p, s)| match s == min_score {
-            true => Some(p),
-            false => None,
-        })
-        .flat_map(|p| p.iter().map(|(pos, _dir)| pos))
-        .unique()
-        .count();
-
-    Ok(n_spaces.to_string())
-}
-
-// The Reindeer start on the Start Tile (marked S) facing East and need to reach the End Tile
-// (marked E). They can move forward one tile at a time (increasing their score by 1 point),
-// but never into a wall (#). They can also rotate clockwise or counterclockwise 90 degrees
-// at a time (increasing their score by 1000 points).
-//
-// What is the lowest score a Reindeer could possibly get?
-
-fn parse(input: &str) -> (IVec2, IVec2, HashSet<IVec2>) {
-    let mut start: IVec2 = IVec2::default();
-    let mut end: IVec2 = IVec2::default();
-    let mut walls: HashSet<IVec2> = HashSet::new();
-
-    for (y, l) in input.lines().enumerate() {
-        for (x, c) in l.chars().enumerate() {
-            match c {
-                'S' => {
-                    start = IVec2::new(x as i32, y as i32);
-                }
-                'E' => {
-                    end = IVec2::new(x as i32, y as i32);
-                }
-                '#' => {
-                    walls.insert(IVec2::new(x as i32, y as i32));
-                }
-                _ => {}
-            }
-        }
-    }
-
-    (start, end, walls)
+    Ok(tiles.len().into())
 }
 
 #[cfg(test)]
@@ -102,6 +37,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render() -> miette::Result<()> {
+        let input = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+        let maze = ReindeerMaze::parse(input)?;
+        assert_eq!(
+            "###############
+#.......#....O#
+#.#.###.#.###O#
+#.....#.#...#O#
+#.###.#####.#O#
+#.#.#.......#O#
+#.#.#####.###O#
+#..OOOOOOOOO#O#
+###O#O#####O#O#
+#OOO#O....#O#O#
+#O#O#O###.#O#O#
+#OOOOO#...#O#O#
+#O###.#.#.#O#O#
+#O..#.....#OOO#
+###############",
+            maze.render(&maze.best_tiles()?)
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_large_maze() -> miette::Result<()> {
         let input = "#################
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use aoc_input::Source;
+use clap::Parser;
+use day17::{disasm::disassemble, trace::trace, vm::Vm};
+use itertools::Itertools;
+use miette::Context;
+use tracing_subscriber::EnvFilter;
+
+/// Disassemble the day 17 program, optionally running it with a per-instruction trace.
+#[derive(Debug, Parser)]
+#[command(name = "disasm")]
+struct Cli {
+    /// Read the program from this file (or `-` for stdin) instead of `input_day17.txt`.
+    input: Option<PathBuf>,
+    /// Run the program, logging the registers after every instruction.
+    #[arg(short, long)]
+    trace: bool,
+    /// Start with this value in register A instead of the one in the input.
+    #[arg(short, long, requires = "trace")]
+    a: Option<u64>,
+}
+
+fn main() -> miette::Result<()> {
+    let cli = Cli::parse();
+
+    // Tracing is only useful when asked for, so default to showing this crate's trace events.
+    let filter = if cli.trace {
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("day17=trace"))
+    } else {
+        EnvFilter::from_default_env()
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+
+    let source = match cli.input {
+        Some(path) => Source::from_arg(path),
+        None => Source::for_day(17),
+    };
+    let file = source.read()?;
+    let mut vm = Vm::parse(&file).context("parse program")?;
+
+    print!("{}", disassemble(vm.program()));
+
+    if cli.trace {
+        if let Some(a) = cli.a {
+            let mut registers = vm.registers();
+            registers.a = a;
+            vm.reset(registers);
+        }
+        let mut out = vec![];
+        let steps = trace(&mut vm, &mut out).context("trace program")?;
+        println!("Output: {}", out.iter().join(","));
+        println!("Steps: {steps}");
+    }
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::fmt::{self, Write};
+
+use crate::vm::{Combo, Instruction};
+
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Combo::Literal(v) => write!(f, "{v}"),
+            Combo::A => write!(f, "A"),
+            Combo::B => write!(f, "B"),
+            Combo::C => write!(f, "C"),
+        }
+    }
+}
+
+/// The mnemonic and operand, e.g. `adv 3` or `out B`.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Adv(combo) => write!(f, "adv {combo}"),
+            Instruction::Bxl(literal) => write!(f, "bxl {literal}"),
+            Instruction::Bst(combo) => write!(f, "bst {combo}"),
+            Instruction::Jnz(literal) => write!(f, "jnz {literal}"),
+            Instruction::Bxc => write!(f, "bxc"),
+            Instruction::Out(combo) => write!(f, "out {combo}"),
+            Instruction::Bdv(combo) => write!(f, "bdv {combo}"),
+            Instruction::Cdv(combo) => write!(f, "cdv {combo}"),
+        }
+    }
+}
+
+impl Instruction {
+    /// What the instruction does, written as pseudo-code.
+    pub fn describe(&self) -> String {
+        match self {
+            Instruction::Adv(combo) => format!("A = A >> {combo}"),
+            Instruction::Bxl(literal) => format!("B = B ^ {literal}"),
+            Instruction::Bst(combo) => format!("B = {combo} % 8"),
+            Instruction::Jnz(literal) => format!("if A != 0 goto {literal:02}"),
+            Instruction::Bxc => "B = B ^ C".to_string(),
+            Instruction::Out(combo) => format!("out {combo} % 8"),
+            Instruction::Bdv(combo) => format!("B = A >> {combo}"),
+            Instruction::Cdv(combo) => format!("C = A >> {combo}"),
+        }
+    }
+}
+
+/// A listing of `program` with one instruction per line, prefixed by its address:
+///
+/// ```text
+/// 00: adv 3     ; A = A >> 3
+/// 02: out A     ; out A % 8
+/// 04: jnz 0     ; if A != 0 goto 00
+/// ```
+///
+/// Instructions using the reserved combo operand 7, and a trailing opcode without an operand,
+/// are listed as raw values rather than rejected.
+pub fn disassemble(program: &[u8]) -> String {
+    let mut listing = String::new();
+    for (i, chunk) in program.chunks(2).enumerate() {
+        let ip = i * 2;
+        let line = match *chunk {
+            [opcode, operand] => match Instruction::decode(opcode, operand) {
+                Some(instruction) => {
+                    let mnemonic = instruction.to_string();
+                    format!("{mnemonic:<9} ; {}", instruction.describe())
+                }
+                None => format!("{opcode} {operand:<7} ; reserved combo operand"),
+            },
+            [opcode] => format!("{opcode:<9} ; missing operand, halts"),
+            _ => unreachable!("chunks(2) yields one or two values"),
+        };
+        writeln!(listing, "{ip:02}: {line}").expect("writing to a String cannot fail");
+    }
+    listing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_disassemble() {
+        assert_eq!(
+            "00: adv 3     ; A = A >> 3
+02: out A     ; out A % 8
+04: jnz 0     ; if A != 0 goto 00
+",
+            disassemble(&[0, 3, 5, 4, 3, 0])
+        );
+    }
+
+    #[rstest]
+    #[case(0, 1, "adv 1")]
+    #[case(0, 5, "adv B")]
+    #[case(1, 7, "bxl 7")]
+    #[case(2, 4, "bst A")]
+    #[case(3, 2, "jnz 2")]
+    #[case(4, 6, "bxc")]
+    #[case(5, 5, "out B")]
+    #[case(6, 6, "bdv C")]
+    #[case(7, 5, "cdv B")]
+    fn test_mnemonics(#[case] opcode: u8, #[case] operand: u8, #[case] expected: &str) {
+        let instruction = Instruction::decode(opcode, operand).expect("valid instruction");
+        assert_eq!(expected, instruction.to_string());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            "00: 2 7       ; reserved combo operand
+02: 3         ; missing operand, halts
+",
+            disassemble(&[2, 7, 3])
+        );
+    }
+}
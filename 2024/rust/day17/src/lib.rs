@@ -1,2 +1,5 @@
+pub mod disasm;
 pub mod part1;
 pub mod part2;
+pub mod trace;
+pub mod vm;
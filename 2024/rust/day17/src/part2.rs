@@ -1,17 +1,194 @@
+use aoc_answer::Answer;
+use miette::miette;
+
+use crate::vm::{Combo, Instruction, Registers, Vm};
+
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String> {
-    todo!("day17 - part 2");
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let vm = Vm::parse(input)?;
+    let program = vm.program();
+    let Registers { b, c, .. } = vm.registers();
+
+    let a = match Pass::analyse(program) {
+        Some(pass) => search(program, |a, i| pass.output(a) == program[i]),
+        None if shifts_by_three(program) => {
+            search(program, |a, i| run(program, a, b, c) == program[i..])
+        }
+        None => {
+            return Err(miette!(
+                "can only search programs that end with `jnz 0` and shift A by 3 bits per loop"
+            ))
+        }
+    };
+
+    a.map(Answer::from).ok_or(miette!(
+        "no value of register A makes the program print itself"
+    ))
+}
+
+/// Build register A an octal digit at a time, starting from the most significant.
+///
+/// When every pass through the program's loop shifts A right by 3 bits and prints a value, the
+/// last value printed only depends on the top digit of A, the one before on the top two digits,
+/// and so on. So extend every candidate by each of the 8 possible next digits and keep those for
+/// which `matches(a, i)` says A = `a` prints `program[i..]`.
+fn search(program: &[u8], mut matches: impl FnMut(u64, usize) -> bool) -> Option<u64> {
+    let mut candidates = vec![0];
+    for i in (0..program.len()).rev() {
+        candidates = candidates
+            .iter()
+            .flat_map(|&a| (0..8).map(move |digit| a << 3 | digit))
+            // A leading zero digit would print nothing, so A is never 0 at any step.
+            .filter(|&a| a > 0 && matches(a, i))
+            .collect();
+    }
+    candidates.into_iter().min()
+}
+
+/// The instructions before a trailing `jnz 0`, if the program ends with one.
+fn loop_body(program: &[u8]) -> Option<Vec<Instruction>> {
+    let (body, [3, 0]) = program.split_last_chunk::<2>()? else {
+        return None;
+    };
+    body.chunks(2)
+        .map(|i| match *i {
+            [opcode, operand] => Instruction::decode(opcode, operand),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether `program` is a single loop closed by a trailing `jnz 0` that contains `adv 3`, which
+/// is all [`search`] needs.
+fn shifts_by_three(program: &[u8]) -> bool {
+    let Some(body) = loop_body(program) else {
+        return false;
+    };
+    let shift = Instruction::Adv(Combo::Literal(3));
+    body.contains(&shift)
+        && body.iter().all(|&i| {
+            !matches!(i, Instruction::Jnz(_)) && (!matches!(i, Instruction::Adv(_)) || i == shift)
+        })
+}
+
+/// The loop body of a program that can be checked one pass at a time.
+///
+/// This holds for the usual puzzle input: the body shifts A by 3 bits, prints exactly once, and
+/// sets B and C from A before reading them, so what a pass prints only depends on A.
+struct Pass {
+    body: Vec<u8>,
+}
+
+impl Pass {
+    fn analyse(program: &[u8]) -> Option<Self> {
+        if !shifts_by_three(program) {
+            return None;
+        }
+        let instructions = loop_body(program)?;
+
+        let (mut b_set, mut c_set) = (false, false);
+        for instruction in &instructions {
+            let combo = match instruction {
+                Instruction::Adv(c)
+                | Instruction::Bst(c)
+                | Instruction::Out(c)
+                | Instruction::Bdv(c)
+                | Instruction::Cdv(c) => Some(*c),
+                _ => None,
+            };
+            let reads_b = matches!(instruction, Instruction::Bxl(_) | Instruction::Bxc)
+                || combo == Some(Combo::B);
+            let reads_c = matches!(instruction, Instruction::Bxc) || combo == Some(Combo::C);
+            if (reads_b && !b_set) || (reads_c && !c_set) {
+                return None;
+            }
+            b_set |= matches!(
+                instruction,
+                Instruction::Bxl(_) | Instruction::Bst(_) | Instruction::Bxc | Instruction::Bdv(_)
+            );
+            c_set |= matches!(instruction, Instruction::Cdv(_));
+        }
+
+        let outputs = instructions
+            .iter()
+            .filter(|i| matches!(i, Instruction::Out(_)))
+            .count();
+        (outputs == 1).then(|| Self {
+            body: program[..program.len() - 2].to_vec(),
+        })
+    }
+
+    /// The value printed by one pass through the loop starting with A = `a`.
+    fn output(&self, a: u64) -> u8 {
+        run(&self.body, a, 0, 0)[0]
+    }
+}
+
+/// Run a program that has been checked by [`shifts_by_three`] and collect what it prints.
+fn run(program: &[u8], a: u64, b: u64, c: u64) -> Vec<u8> {
+    Vm::new(program.to_vec(), Registers { a, b, c })
+        .and_then(|mut vm| vm.output())
+        .expect("programs that shift A by 3 bits per loop should run to completion")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        let input = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+        assert_eq!("117440", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_fallback_agrees() {
+        let program = [0, 3, 5, 4, 3, 0];
+        assert_eq!(
+            Some(117440),
+            search(&program, |a, i| run(&program, a, 0, 0) == program[i..])
+        );
+    }
+
+    // Each pass sets B from the low bits of A, mixes in C = A >> B and prints B, like real inputs.
+    #[rstest]
+    #[case(&[2, 4, 1, 3, 7, 5, 4, 0, 1, 3, 0, 3, 5, 5, 3, 0], true)]
+    #[case(&[2, 4, 1, 2, 7, 5, 1, 3, 4, 3, 5, 5, 0, 3, 3, 0], true)]
+    // B is read before it is set, so a pass depends on the one before.
+    #[case(&[1, 1, 0, 3, 5, 5, 3, 0], false)]
+    // Two outputs per pass.
+    #[case(&[0, 3, 5, 4, 5, 4, 3, 0], false)]
+    fn test_analyse(#[case] program: &[u8], #[case] per_pass: bool) {
+        assert_eq!(per_pass, Pass::analyse(program).is_some());
+        assert!(shifts_by_three(program));
+    }
+
+    #[rstest]
+    #[case(&[2, 4, 1, 3, 7, 5, 4, 0, 1, 3, 0, 3, 5, 5, 3, 0], 108107574778365)]
+    #[case(&[2, 4, 1, 2, 7, 5, 1, 3, 4, 3, 5, 5, 0, 3, 3, 0], 37221334433268)]
+    fn test_quine(#[case] program: &[u8], #[case] expected: u64) {
+        let pass = Pass::analyse(program).expect("program is analysable");
+        let a = search(program, |a, i| pass.output(a) == program[i]).expect("quine exists");
+        assert_eq!(expected, a);
+        assert_eq!(program, run(program, a, 0, 0));
+        assert_eq!(
+            Some(a),
+            search(program, |a, i| run(program, a, 0, 0) == program[i..])
+        );
+    }
+
+    #[rstest]
+    #[case(&[0, 1, 5, 4, 3, 0])]
+    #[case(&[0, 3, 5, 4])]
+    #[case(&[0, 3, 5, 7, 3, 0])]
+    #[case(&[0, 3, 3, 0, 3, 0])]
+    fn test_unsupported(#[case] program: &[u8]) {
+        assert!(!shifts_by_three(program));
+    }
 }
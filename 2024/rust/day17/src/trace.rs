@@ -0,0 +1,113 @@
+use crate::vm::{Output, Vm, VmError};
+
+/// Run `vm` to completion like [`Vm::run`], emitting a `TRACE` event for every instruction with
+/// the instruction pointer, the instruction and the registers after it ran.
+///
+/// Returns how many instructions were executed.
+pub fn trace(vm: &mut Vm, out: &mut impl Output) -> Result<usize, VmError> {
+    let mut steps = 0;
+    loop {
+        let ip = vm.ip();
+        let Some(instruction) = vm.step(out)? else {
+            return Ok(steps);
+        };
+        steps += 1;
+        let registers = vm.registers();
+        tracing::trace!(
+            step = steps,
+            ip,
+            %instruction,
+            a = registers.a,
+            b = registers.b,
+            c = registers.c,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        fmt,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        Event, Subscriber,
+    };
+    use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+    use super::*;
+    use crate::vm::Registers;
+
+    type Fields = BTreeMap<String, String>;
+
+    /// Collects the fields of every event, formatted with `Debug`.
+    #[derive(Clone, Default)]
+    struct Events(Arc<Mutex<Vec<Fields>>>);
+
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl Visit for Visitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for Events {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let mut fields = Fields::new();
+            event.record(&mut Visitor(&mut fields));
+            self.0.lock().unwrap().push(fields);
+        }
+    }
+
+    #[test]
+    fn test_trace_events() -> miette::Result<()> {
+        let registers = Registers {
+            a: 729,
+            ..Default::default()
+        };
+        let mut vm = Vm::new(vec![0, 1, 5, 4, 3, 0], registers)?;
+
+        let events = Events::default();
+        let subscriber = tracing_subscriber::registry().with(events.clone());
+        let steps = tracing::subscriber::with_default(subscriber, || trace(&mut vm, &mut vec![]))?;
+
+        let events = events.0.lock().unwrap();
+        assert_eq!(steps, events.len());
+        let first = &events[0];
+        assert_eq!("1", first["step"]);
+        assert_eq!("0", first["ip"]);
+        assert_eq!("adv 1", first["instruction"]);
+        assert_eq!("364", first["a"]);
+        assert_eq!("0", first["b"]);
+        assert_eq!("0", first["c"]);
+        // `out A` is the second instruction of every pass.
+        assert_eq!("out A", events[1]["instruction"]);
+        assert_eq!("2", events[1]["ip"]);
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_trace_matches_run() -> miette::Result<()> {
+        let program = vec![0, 1, 5, 4, 3, 0];
+        let registers = Registers {
+            a: 729,
+            ..Default::default()
+        };
+
+        let mut traced = Vm::new(program.clone(), registers)?;
+        let mut out = vec![];
+        let steps = trace(&mut traced, &mut out)?;
+
+        let mut vm = Vm::new(program, registers)?;
+        assert_eq!(vm.output()?, out);
+        assert_eq!(vm.registers(), traced.registers());
+        // Ten passes of three instructions each.
+        assert_eq!(30, steps);
+        Ok(())
+    }
+}
@@ -0,0 +1,417 @@
+use aoc_parse::ParseError;
+use miette::Diagnostic;
+use thiserror::Error;
+use winnow::{
+    ascii::{dec_uint, line_ending},
+    combinator::{preceded, separated, seq, terminated},
+    Parser,
+};
+
+/// The registers of the 3-bit computer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+/// A combo operand: 0-3 are literal values and 4-6 name a register. 7 is reserved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combo {
+    Literal(u8),
+    A,
+    B,
+    C,
+}
+
+impl Combo {
+    pub fn decode(operand: u8) -> Option<Self> {
+        match operand {
+            0..=3 => Some(Combo::Literal(operand)),
+            4 => Some(Combo::A),
+            5 => Some(Combo::B),
+            6 => Some(Combo::C),
+            _ => None,
+        }
+    }
+
+    pub fn value(self, registers: &Registers) -> u64 {
+        match self {
+            Combo::Literal(v) => v.into(),
+            Combo::A => registers.a,
+            Combo::B => registers.b,
+            Combo::C => registers.c,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// Divide A by 2 to the power of the combo operand, truncating, and store the result in A.
+    Adv(Combo),
+    /// XOR B with the literal operand.
+    Bxl(u8),
+    /// Store the combo operand modulo 8 in B.
+    Bst(Combo),
+    /// Jump to the literal operand unless A is 0.
+    Jnz(u8),
+    /// XOR B with C. The operand is read but ignored.
+    Bxc,
+    /// Output the combo operand modulo 8.
+    Out(Combo),
+    /// Like `adv`, but store the result in B.
+    Bdv(Combo),
+    /// Like `adv`, but store the result in C.
+    Cdv(Combo),
+}
+
+impl Instruction {
+    /// Decode an opcode and its operand, or `None` when a combo operand is the reserved 7.
+    pub fn decode(opcode: u8, operand: u8) -> Option<Self> {
+        let combo = Combo::decode(operand);
+        Some(match opcode & 7 {
+            0 => Instruction::Adv(combo?),
+            1 => Instruction::Bxl(operand),
+            2 => Instruction::Bst(combo?),
+            3 => Instruction::Jnz(operand),
+            4 => Instruction::Bxc,
+            5 => Instruction::Out(combo?),
+            6 => Instruction::Bdv(combo?),
+            _ => Instruction::Cdv(combo?),
+        })
+    }
+}
+
+/// Where the values a program outputs go.
+pub trait Output {
+    fn output(&mut self, value: u8);
+}
+
+impl Output for Vec<u8> {
+    fn output(&mut self, value: u8) {
+        self.push(value);
+    }
+}
+
+/// Discards everything, for when only the final registers matter.
+impl Output for () {
+    fn output(&mut self, _value: u8) {}
+}
+
+/// The 3-bit computer: a program, its registers and an instruction pointer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vm {
+    registers: Registers,
+    program: Vec<u8>,
+    ip: usize,
+    steps: usize,
+    step_limit: usize,
+}
+
+impl Vm {
+    /// How many instructions [`Vm::run`] executes before deciding the program will not halt.
+    pub const DEFAULT_STEP_LIMIT: usize = 1_000_000;
+
+    pub fn new(program: Vec<u8>, registers: Registers) -> Result<Self, VmError> {
+        if let Some((index, &value)) = program.iter().enumerate().find(|(_, &v)| v > 7) {
+            return Err(VmError::NotThreeBit { index, value });
+        }
+        Ok(Self {
+            registers,
+            program,
+            ip: 0,
+            steps: 0,
+            step_limit: Self::DEFAULT_STEP_LIMIT,
+        })
+    }
+
+    /// Parse the puzzle input: the three registers, a blank line and the program.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (registers, program) = aoc_parse::parse(
+            input,
+            seq!(
+                seq!(Registers {
+                    a: parse_register_a,
+                    b: parse_register_b,
+                    c: parse_register_c,
+                }),
+                _: line_ending,
+                parse_program,
+            ),
+        )?;
+        Ok(Self::new(program, registers).expect("the parser only accepts 3-bit values"))
+    }
+
+    pub fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = step_limit;
+        self
+    }
+
+    /// Start over from the beginning of the program with new registers.
+    pub fn reset(&mut self, registers: Registers) {
+        self.registers = registers;
+        self.ip = 0;
+        self.steps = 0;
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// The program halts when the instruction pointer runs past the last full instruction.
+    pub fn is_halted(&self) -> bool {
+        self.ip + 1 >= self.program.len()
+    }
+
+    /// The instruction at the instruction pointer, or `None` once halted.
+    pub fn current(&self) -> Result<Option<Instruction>, VmError> {
+        match self.program.get(self.ip..self.ip + 2) {
+            Some(&[opcode, operand]) => Instruction::decode(opcode, operand)
+                .map(Some)
+                .ok_or(VmError::ReservedOperand { ip: self.ip }),
+            _ => Ok(None),
+        }
+    }
+
+    /// Execute one instruction and return it, or `None` if the program had already halted.
+    pub fn step(&mut self, out: &mut impl Output) -> Result<Option<Instruction>, VmError> {
+        let Some(instruction) = self.current()? else {
+            return Ok(None);
+        };
+        if self.steps == self.step_limit {
+            return Err(VmError::StepLimit {
+                limit: self.step_limit,
+            });
+        }
+        self.steps += 1;
+
+        let r = &mut self.registers;
+        let divide = |a: u64, combo: Combo, r: &Registers| {
+            u32::try_from(combo.value(r))
+                .ok()
+                .and_then(|shift| a.checked_shr(shift))
+                .unwrap_or(0)
+        };
+        match instruction {
+            Instruction::Adv(combo) => r.a = divide(r.a, combo, r),
+            Instruction::Bxl(literal) => r.b ^= u64::from(literal),
+            Instruction::Bst(combo) => r.b = combo.value(r) % 8,
+            Instruction::Jnz(target) if r.a != 0 => {
+                self.ip = target.into();
+                return Ok(Some(instruction));
+            }
+            Instruction::Jnz(_) => {}
+            Instruction::Bxc => r.b ^= r.c,
+            Instruction::Out(combo) => out.output((combo.value(r) % 8) as u8),
+            Instruction::Bdv(combo) => r.b = divide(r.a, combo, r),
+            Instruction::Cdv(combo) => r.c = divide(r.a, combo, r),
+        }
+        self.ip += 2;
+        Ok(Some(instruction))
+    }
+
+    /// Run until the program halts.
+    pub fn run(&mut self, out: &mut impl Output) -> Result<(), VmError> {
+        while self.step(out)?.is_some() {}
+        Ok(())
+    }
+
+    /// Run until the program halts and collect what it output.
+    pub fn output(&mut self) -> Result<Vec<u8>, VmError> {
+        let mut out = vec![];
+        self.run(&mut out)?;
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Error, Diagnostic, PartialEq, Eq)]
+pub enum VmError {
+    #[error("program value {value} at position {index} is not a 3-bit number")]
+    #[diagnostic(code(day17::vm::not_three_bit))]
+    NotThreeBit { index: usize, value: u8 },
+
+    #[error("the instruction at {ip} uses the reserved combo operand 7")]
+    #[diagnostic(code(day17::vm::reserved_operand))]
+    ReservedOperand { ip: usize },
+
+    #[error("the program did not halt within {limit} steps")]
+    #[diagnostic(
+        code(day17::vm::step_limit),
+        help("it is probably stuck in a loop; raise the limit with `Vm::with_step_limit` if not")
+    )]
+    StepLimit { limit: usize },
+}
+
+fn parse_register_a(input: &mut &str) -> winnow::PResult<u64> {
+    terminated(preceded("Register A: ", dec_uint), line_ending).parse_next(input)
+}
+
+fn parse_register_b(input: &mut &str) -> winnow::PResult<u64> {
+    terminated(preceded("Register B: ", dec_uint), line_ending).parse_next(input)
+}
+
+fn parse_register_c(input: &mut &str) -> winnow::PResult<u64> {
+    terminated(preceded("Register C: ", dec_uint), line_ending).parse_next(input)
+}
+
+fn parse_program(input: &mut &str) -> winnow::PResult<Vec<u8>> {
+    preceded(
+        "Program: ",
+        separated(1.., dec_uint::<_, u8, _>.verify(|v| *v < 8), ","),
+    )
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn run(program: &[u8], registers: Registers) -> Result<(Vec<u8>, Registers), VmError> {
+        let mut vm = Vm::new(program.to_vec(), registers)?;
+        let output = vm.output()?;
+        Ok((output, vm.registers()))
+    }
+
+    // The small examples from the puzzle description.
+    #[rstest]
+    #[case(&[2, 6], Registers { c: 9, ..Default::default() }, vec![], Registers { b: 1, c: 9, ..Default::default() })]
+    #[case(&[5, 0, 5, 1, 5, 4], Registers { a: 10, ..Default::default() }, vec![0, 1, 2], Registers { a: 10, ..Default::default() })]
+    #[case(&[0, 1, 5, 4, 3, 0], Registers { a: 2024, ..Default::default() }, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0], Registers::default())]
+    #[case(&[1, 7], Registers { b: 29, ..Default::default() }, vec![], Registers { b: 26, ..Default::default() })]
+    #[case(&[4, 0], Registers { b: 2024, c: 43690, ..Default::default() }, vec![], Registers { b: 44354, c: 43690, ..Default::default() })]
+    fn test_examples(
+        #[case] program: &[u8],
+        #[case] registers: Registers,
+        #[case] output: Vec<u8>,
+        #[case] after: Registers,
+    ) -> miette::Result<()> {
+        assert_eq!((output, after), run(program, registers)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_registers() -> miette::Result<()> {
+        // Dividing by 2^40 overflowed the old i32 registers.
+        let registers = Registers {
+            a: 1 << 45,
+            b: 40,
+            c: 0,
+        };
+        assert_eq!(
+            (vec![0], Registers { a: 32, b: 40, c: 0 }),
+            run(&[0, 5, 5, 4], registers)?
+        );
+        let registers = Registers { a: 5, b: 200, c: 0 };
+        assert_eq!(0, run(&[0, 5], registers)?.1.a);
+        Ok(())
+    }
+
+    #[test]
+    fn test_step() -> miette::Result<()> {
+        let mut vm = Vm::new(vec![0, 1, 5, 4, 3, 0], Registers { a: 4, b: 0, c: 0 })?;
+        let mut out = vec![];
+        assert_eq!(
+            Some(Instruction::Adv(Combo::Literal(1))),
+            vm.step(&mut out)?
+        );
+        assert_eq!(Some(Instruction::Out(Combo::A)), vm.step(&mut out)?);
+        assert_eq!(vec![2], out);
+        assert_eq!(Some(Instruction::Jnz(0)), vm.step(&mut out)?);
+        assert_eq!(0, vm.ip());
+        vm.run(&mut out)?;
+        assert!(vm.is_halted());
+        assert_eq!(None, vm.step(&mut out)?);
+        assert_eq!(vec![2, 1, 0], out);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[2, 4, 5, 7], VmError::ReservedOperand { ip: 2 })]
+    #[case(&[3, 0], VmError::StepLimit { limit: 100 })]
+    fn test_errors(#[case] program: &[u8], #[case] expected: VmError) -> miette::Result<()> {
+        let mut vm =
+            Vm::new(program.to_vec(), Registers { a: 1, b: 0, c: 0 })?.with_step_limit(100);
+        assert_eq!(Err(expected), vm.output());
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_three_bit() {
+        assert_eq!(
+            Err(VmError::NotThreeBit { index: 1, value: 8 }),
+            Vm::new(vec![0, 8], Registers::default())
+        );
+    }
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let vm = Vm::parse(
+            "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+",
+        )?;
+        assert_eq!(Registers { a: 729, b: 0, c: 0 }, vm.registers());
+        assert_eq!(&[0, 1, 5, 4, 3, 0], vm.program());
+
+        let err =
+            Vm::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,9").unwrap_err();
+        assert_eq!(5, err.line());
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg_a() -> miette::Result<()> {
+        let mut input = "Register A: 729\n";
+        assert_eq!(
+            729,
+            parse_register_a(&mut input)
+                .map_err(|e| miette::miette!("could not parse register A {}", e))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg_b() -> miette::Result<()> {
+        let mut input = "Register B: 0\n";
+        assert_eq!(
+            0,
+            parse_register_b(&mut input)
+                .map_err(|e| miette::miette!("could not parse register B {}", e))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg_c() -> miette::Result<()> {
+        let mut input = "Register C: 0\n";
+        assert_eq!(
+            0,
+            parse_register_c(&mut input)
+                .map_err(|e| miette::miette!("could not parse register C {}", e))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_prog() -> miette::Result<()> {
+        let mut input = "Program: 0,1,5,4,3,0";
+        assert_eq!(
+            vec![0, 1, 5, 4, 3, 0],
+            parse_program(&mut input)
+                .map_err(|e| miette::miette!("could not parse program {}", e))?
+        );
+        Ok(())
+    }
+}
@@ -6,16 +6,13 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(
-        divan::black_box(&mut include_str!("../../../input_day18.txt")),
-        1024,
-        72,
-    )
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_input::load(18).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| part1::process(divan::black_box(&mut input.as_str()), 1024, 70).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!("../../../input_day18.txt"))).unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_input::load(18).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| part2::process(divan::black_box(&mut input.as_str()), 70).unwrap());
 }
@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let mut file = include_str!("../../../../input_day18.txt");
-    let result = process(&mut file, 70).context("process part 2")?;
+    let file = aoc_input::load_cli(18)?;
+    let result = process(&mut file.as_str(), 70).context("process part 2")?;
     println!("Part 2: {}", result);
     Ok(())
 }
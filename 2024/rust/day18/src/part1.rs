@@ -1,58 +1,55 @@
+use aoc_answer::Answer;
+use aoc_grid::Grid;
+use aoc_parse::ParseError;
+use aoc_search::astar;
 use glam::IVec2;
-use pathfinding::prelude::dijkstra;
+use miette::miette;
 use winnow::{
     ascii::{dec_int, line_ending},
     combinator::{separated, seq},
-    Parser,
 };
 
-const DIRECTIONS: [IVec2; 4] = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
-
 #[tracing::instrument]
-pub fn process(input: &mut &str, n_bytes: usize, width: usize) -> miette::Result<String> {
+pub fn process(input: &mut &str, n_bytes: usize, width: usize) -> miette::Result<Answer> {
     let start = IVec2::new(0, 0);
     let end = IVec2::new(width as i32, width as i32);
-    let bytes = parse(input)
-        .into_iter()
-        .take(n_bytes)
-        .collect::<Vec<IVec2>>();
+    let mut memory = Grid::new(width as i32 + 1, width as i32 + 1, false);
+    // Bytes outside the memory space cannot block anything, as before the move to `Grid`.
+    for byte in parse(input)?.into_iter().take(n_bytes) {
+        if let Some(cell) = memory.get_mut(byte) {
+            *cell = true;
+        }
+    }
 
-    let path_len = dijkstra(
+    let path_len = astar(
         &start,
         |&pos| {
-            let mut new_positions = vec![];
-            for dir in DIRECTIONS {
-                let next_pos = pos + dir;
-                if !bytes.contains(&next_pos)
-                    && (next_pos.x >= 0)
-                    && (next_pos.y >= 0)
-                    && (next_pos.x <= width as i32)
-                    && (next_pos.y <= width as i32)
-                {
-                    new_positions.push(next_pos);
-                }
-            }
-            new_positions.into_iter().map(|p| (p, 1))
+            memory
+                .neighbours4(pos)
+                .filter(|&p| !memory[p])
+                .map(|p| (p, 1))
         },
+        |&pos| (end - pos).abs().element_sum(),
         |&pos| pos == end,
     )
-    .expect("no path found")
+    .ok_or(miette!("the fallen bytes should leave a path to the exit"))?
     .1;
-    Ok(path_len.to_string())
+    Ok(path_len.into())
 }
 
-fn parse(input: &mut &str) -> Vec<IVec2> {
-    separated(
-        1..,
-        seq!(IVec2 {
-            x: dec_int::<_, i32, winnow::error::ErrorKind>,
-            _: ",",
-            y: dec_int::<_, i32, winnow::error::ErrorKind>,
-        }),
-        line_ending,
+fn parse(input: &str) -> Result<Vec<IVec2>, ParseError> {
+    aoc_parse::parse(
+        input,
+        separated(
+            1..,
+            seq!(IVec2 {
+                x: dec_int,
+                _: ",",
+                y: dec_int,
+            }),
+            line_ending,
+        ),
     )
-    .parse_next(input)
-    .expect("AoC should provide valid input.")
 }
 
 #[cfg(test)]
@@ -89,4 +86,10 @@ mod tests {
         assert_eq!("22", process(&mut input, 12, 6)?);
         Ok(())
     }
+
+    #[test]
+    fn test_byte_outside_memory() -> miette::Result<()> {
+        assert_eq!("4", process(&mut "9,9\n1,0", 2, 2)?);
+        Ok(())
+    }
 }
@@ -1,66 +1,54 @@
+use aoc_answer::Answer;
+use aoc_grid::Grid;
+use aoc_parse::ParseError;
+use aoc_search::bfs;
 use glam::IVec2;
-use pathfinding::prelude::dijkstra;
+use miette::miette;
 use winnow::{
     ascii::{dec_int, line_ending},
     combinator::{separated, seq},
-    Parser,
 };
 
-const DIRECTIONS: [IVec2; 4] = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
-
 #[tracing::instrument]
-pub fn process(input: &mut &str, width: usize) -> miette::Result<String> {
-    let bytes = parse(input);
-    let mut byte_idx = 1;
-    loop {
-        match run(width, &bytes[..byte_idx]) {
-            Some(_) => {
-                byte_idx += 1;
+pub fn process(input: &mut &str, width: usize) -> miette::Result<Answer> {
+    let mut memory = Grid::new(width as i32 + 1, width as i32 + 1, false);
+    let fatal_byte = parse(input)?
+        .into_iter()
+        .find(|&byte| match memory.get_mut(byte) {
+            Some(cell) => {
+                *cell = true;
+                run(&memory).is_none()
             }
-            None => break,
-        }
-    }
-    let fatal_byte = bytes[byte_idx - 1];
+            // Outside the memory space, so it cannot cut anything off.
+            None => false,
+        })
+        .ok_or(miette!("no byte cuts off the exit"))?;
 
-    Ok(format!("{},{}", fatal_byte.x, fatal_byte.y))
+    Ok(format!("{},{}", fatal_byte.x, fatal_byte.y).into())
 }
 
-fn run(width: usize, bytes: &[IVec2]) -> Option<(Vec<IVec2>, i32)> {
-    let start = IVec2::new(0, 0);
-    let end = IVec2::new(width as i32, width as i32);
-    dijkstra(
-        &start,
-        |&pos| {
-            let mut new_positions = vec![];
-            for dir in DIRECTIONS {
-                let next_pos = pos + dir;
-                if !bytes.contains(&next_pos)
-                    && (next_pos.x >= 0)
-                    && (next_pos.y >= 0)
-                    && (next_pos.x <= width as i32)
-                    && (next_pos.y <= width as i32)
-                {
-                    new_positions.push(next_pos);
-                }
-            }
-            new_positions.into_iter().map(|p| (p, 1))
-        },
+fn run(memory: &Grid<bool>) -> Option<Vec<IVec2>> {
+    let end = memory.size() - IVec2::ONE;
+    bfs(
+        &IVec2::ZERO,
+        |&pos| memory.neighbours4(pos).filter(|&p| !memory[p]),
         |&pos| pos == end,
     )
 }
 
-fn parse(input: &mut &str) -> Vec<IVec2> {
-    separated(
-        1..,
-        seq!(IVec2 {
-            x: dec_int::<_, i32, winnow::error::ErrorKind>,
-            _: ",",
-            y: dec_int::<_, i32, winnow::error::ErrorKind>,
-        }),
-        line_ending,
+fn parse(input: &str) -> Result<Vec<IVec2>, ParseError> {
+    aoc_parse::parse(
+        input,
+        separated(
+            1..,
+            seq!(IVec2 {
+                x: dec_int,
+                _: ",",
+                y: dec_int,
+            }),
+            line_ending,
+        ),
     )
-    .parse_next(input)
-    .expect("AoC should provide valid input.")
 }
 
 #[cfg(test)]
@@ -97,4 +85,16 @@ mod tests {
         assert_eq!("6,1", process(&mut input, 6)?);
         Ok(())
     }
+
+    #[test]
+    fn test_byte_outside_memory() -> miette::Result<()> {
+        assert_eq!("0,1", process(&mut "9,9\n1,0\n0,1", 2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_exit_never_cut_off() {
+        let err = process(&mut "9,9\n1,1", 2).unwrap_err();
+        assert_eq!("no byte cuts off the exit", err.to_string());
+    }
 }
@@ -1,5 +1,5 @@
-use miette::{miette, Context};
-
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use winnow::{
     ascii::{alpha1, line_ending},
     combinator::{separated, seq, terminated},
@@ -7,42 +7,40 @@ use winnow::{
 };
 
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let (towels, display) =
-        parse(input).map_err(|e| miette!("AoC should provide valid input {}", e))?;
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let (towels, display) = parse(input)?;
 
     let num_valid = display
         .into_iter()
         .filter(|&d| check_display(d, &towels))
         .count();
 
-    Ok(num_valid.to_string())
+    Ok(num_valid.into())
 }
 
 fn check_display(display: &str, towels: &Vec<&str>) -> bool {
-    towels
-        .iter()
-        .map(|towel| {
-            if display.starts_with(*towel) {
-                let new_display = &display[towel.len()..];
-                if new_display.is_empty() {
-                    return true;
-                }
-                check_display(new_display, towels)
-            } else {
-                false
+    towels.iter().any(|towel| {
+        if display.starts_with(*towel) {
+            let new_display = &display[towel.len()..];
+            if new_display.is_empty() {
+                return true;
             }
-        })
-        .any(|v| v)
+            check_display(new_display, towels)
+        } else {
+            false
+        }
+    })
 }
 
-fn parse<'a>(input: &mut &'a str) -> winnow::PResult<(Vec<&'a str>, Vec<&'a str>)> {
-    seq!(
-        parse_towels,
-        _: line_ending,
-        parse_display
+fn parse(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    aoc_parse::parse(
+        input,
+        seq!(
+            parse_towels,
+            _: line_ending,
+            parse_display
+        ),
     )
-    .parse_next(input)
 }
 
 fn parse_towels<'a>(input: &mut &'a str) -> winnow::PResult<Vec<&'a str>> {
@@ -81,6 +79,6 @@ bbrgwb";
     #[test]
     fn test_slice() {
         let bob = "abc";
-        assert_eq!(&bob[0..0 + 1], "a");
+        assert_eq!(&bob[0..1], "a");
     }
 }
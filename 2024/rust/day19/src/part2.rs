@@ -1,6 +1,7 @@
 use cached::proc_macro::cached;
-use miette::miette;
 
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use winnow::{
     ascii::{alpha1, line_ending},
@@ -9,9 +10,8 @@ use winnow::{
 };
 
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let (towels, displays) =
-        parse(input).map_err(|e| miette!("AoC should provide valid input {}", e))?;
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let (towels, displays) = parse(input)?;
 
     let num_valid: usize = displays
         .par_iter()
@@ -20,7 +20,7 @@ pub fn process(input: &mut &str) -> miette::Result<String> {
             find_ways(d, &towels)
         })
         .sum();
-    Ok(num_valid.to_string())
+    Ok(num_valid.into())
 }
 
 #[cached(key = "String", convert = r##"{ format!("{display}") }"##)]
@@ -28,8 +28,7 @@ fn find_ways(display: &str, towels: &[&str]) -> usize {
     towels
         .iter()
         .filter_map(|&towel| {
-            if display.starts_with(towel) {
-                let new_display = &display[towel.len()..];
+            if let Some(new_display) = display.strip_prefix(towel) {
                 if new_display.is_empty() {
                     return Some(1);
                 }
@@ -41,13 +40,15 @@ fn find_ways(display: &str, towels: &[&str]) -> usize {
         .sum()
 }
 
-fn parse<'a>(input: &mut &'a str) -> winnow::PResult<(Vec<&'a str>, Vec<&'a str>)> {
-    seq!(
-        parse_towels,
-        _: line_ending,
-        parse_display
+fn parse(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    aoc_parse::parse(
+        input,
+        seq!(
+            parse_towels,
+            _: line_ending,
+            parse_display
+        ),
     )
-    .parse_next(input)
 }
 
 fn parse_towels<'a>(input: &mut &'a str) -> winnow::PResult<Vec<&'a str>> {
@@ -6,15 +6,13 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(
-        divan::black_box(include_str!("../../../input_day20.txt")),
-        100,
-    )
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_input::load(20).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| part1::process(divan::black_box(&input), 100).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!("../../../input_day20.txt"))).unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_input::load(20).expect("puzzle input should be available to benchmark");
+    bencher.bench(|| part2::process(divan::black_box(&input), part2::CHEAT, 100).unwrap());
 }
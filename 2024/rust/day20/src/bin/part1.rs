@@ -5,8 +5,8 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../../../input_day20.txt");
-    let result = process(file, 100).context("process part 1")?;
+    let file = aoc_input::load_cli(20)?;
+    let result = process(&file, 100).context("process part 1")?;
     println!("Part 1: {}", result);
     Ok(())
 }
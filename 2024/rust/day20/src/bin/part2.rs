@@ -1,12 +1,12 @@
-use day20::part2::process;
+use day20::part2::{process, CHEAT};
 use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../../../input_day20.txt");
-    let result = process(file).context("process part 2")?;
+    let file = aoc_input::load_cli(20)?;
+    let result = process(&file, CHEAT, 100).context("process part 2")?;
     println!("Part 2: {}", result);
     Ok(())
 }
@@ -1,6 +1,14 @@
+use aoc_answer::Answer;
+
+use crate::race::Race;
+
+/// Cheats may last up to twenty picoseconds.
+pub const CHEAT: usize = 20;
+
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String> {
-    todo!("day20 - part 2");
+pub fn process(input: &str, cheat: usize, savings: usize) -> miette::Result<Answer> {
+    let race = Race::parse(input)?;
+    Ok(race.count_cheats(cheat, savings).into())
 }
 
 #[cfg(test)]
@@ -9,9 +17,22 @@ mod tests {
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        let input = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+        assert_eq!("285", process(input, CHEAT, 50)?);
         Ok(())
     }
 }
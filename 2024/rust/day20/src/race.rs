@@ -0,0 +1,121 @@
+use aoc_grid::Grid;
+use aoc_search::bfs_distances;
+use glam::IVec2;
+use miette::miette;
+
+/// The race track with the number of picoseconds from the start and to the end for every open
+/// position.
+#[derive(Debug)]
+pub struct Race {
+    from_start: Grid<Option<usize>>,
+    to_end: Grid<Option<usize>>,
+    /// The fastest time without cheating.
+    base_time: usize,
+}
+
+impl Race {
+    pub fn parse(input: &str) -> miette::Result<Self> {
+        let track = Grid::parse(input)?;
+        let start = track
+            .find(&'S')
+            .ok_or(miette!("track should have a start"))?;
+        let end = track
+            .find(&'E')
+            .ok_or(miette!("track should have an end"))?;
+
+        let distances = |from: IVec2| {
+            let mut grid = track.map(|_| None);
+            let reached = bfs_distances(&from, |&p| {
+                track.neighbours4(p).filter(|&next| track[next] != '#')
+            });
+            for (pos, distance) in reached {
+                grid[pos] = Some(distance);
+            }
+            grid
+        };
+        let from_start = distances(start);
+        let to_end = distances(end);
+        let base_time = from_start[end].ok_or(miette!("the end should be reachable"))?;
+
+        Ok(Self {
+            from_start,
+            to_end,
+            base_time,
+        })
+    }
+
+    pub fn base_time(&self) -> usize {
+        self.base_time
+    }
+
+    /// Count the cheats lasting at most `cheat` picoseconds that save at least `savings`.
+    ///
+    /// A cheat is identified by where it starts and ends, so it jumps from a position on the track
+    /// to any other within Manhattan distance `cheat`, taking that many picoseconds.
+    pub fn count_cheats(&self, cheat: usize, savings: usize) -> usize {
+        let radius = cheat as i32;
+        self.from_start
+            .iter()
+            .filter_map(|(pos, distance)| distance.map(|d| (pos, d)))
+            .map(|(pos, before)| {
+                (-radius..=radius)
+                    .flat_map(|dy| {
+                        let reach = radius - dy.abs();
+                        (-reach..=reach).map(move |dx| IVec2::new(dx, dy))
+                    })
+                    .filter_map(|offset| {
+                        let after = (*self.to_end.get(pos + offset)?)?;
+                        let time = before + offset.abs().element_sum() as usize + after;
+                        Some(self.base_time.saturating_sub(time))
+                    })
+                    .filter(|&saved| saved > 0 && saved >= savings)
+                    .count()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn test_base_time() -> miette::Result<()> {
+        assert_eq!(84, Race::parse(INPUT)?.base_time());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(2, 2, 44)]
+    #[case(2, 20, 5)]
+    #[case(2, 64, 1)]
+    #[case(2, 65, 0)]
+    #[case(20, 50, 285)]
+    #[case(20, 72, 29)]
+    #[case(20, 76, 3)]
+    fn test_count_cheats(
+        #[case] cheat: usize,
+        #[case] savings: usize,
+        #[case] expected: usize,
+    ) -> miette::Result<()> {
+        assert_eq!(expected, Race::parse(INPUT)?.count_cheats(cheat, savings));
+        Ok(())
+    }
+}
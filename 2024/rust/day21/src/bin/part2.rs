@@ -1,12 +1,12 @@
-use day21::part2::process;
+use day21::part2::{process, ROBOTS};
 use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../../../input_day21.txt");
-    let result = process(file).context("process part 2")?;
+    let file = aoc_input::load_cli(21)?;
+    let result = process(&file, ROBOTS).context("process part 2")?;
     println!("Part 2: {}", result);
     Ok(())
 }
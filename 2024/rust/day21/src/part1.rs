@@ -1,11 +1,9 @@
-use std::collections::HashMap;
-
+use aoc_answer::Answer;
+use aoc_grid::{Direction, Grid};
+use aoc_search::bfs_all;
 use glam::IVec2;
 use itertools::Itertools;
 use miette::miette;
-use pathfinding::prelude::yen;
-
-const DIRECTIONS: [IVec2; 4] = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
 
 // Robot 1 (029A)
 // +---+---+---+
@@ -53,16 +51,19 @@ const DIRECTIONS: [IVec2; 4] = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
 //
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let res: i32 = input
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let res = input
         .lines()
-        .map(|code| (code, find_path(code)))
-        .map(|(code, key_presses)| {
-            calc_complexity(code, key_presses).expect("AoC codes should be parsable")
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .map(|code| {
+            // Check the code before searching the keypad for buttons it does not have.
+            code_number(code)?;
+            calc_complexity(code, find_path(code))
         })
-        .sum();
+        .sum::<miette::Result<i32>>()?;
 
-    Ok(res.to_string())
+    Ok(res.into())
 }
 
 fn find_path(code: &str) -> String {
@@ -85,62 +86,34 @@ fn find_path(code: &str) -> String {
 
     dir2_pad_paths
         .iter()
-        .filter(|&p| p.len() == dir2_pad_min)
-        .last()
+        .rfind(|&p| p.len() == dir2_pad_min)
         .unwrap()
         .to_owned()
 }
 
 /// Find the location of a given character on a keypad.
-fn find_position(map: &Map, key: &char) -> Option<IVec2> {
-    map.iter()
-        .filter_map(|(k, v)| if v == key { Some(k.clone()) } else { None })
-        .last()
+pub(crate) fn find_position(map: &Map, key: &char) -> Option<IVec2> {
+    map.find(key)
 }
 
-/// Find the shortest path between key presses.
-fn find_shortest_paths(map: &Map, start: &IVec2, end: &IVec2) -> Vec<String> {
-    let keys = map.keys().collect::<Vec<&IVec2>>();
-    let k_paths = yen(
+/// Find every shortest path between key presses.
+pub(crate) fn find_shortest_paths(map: &Map, start: &IVec2, end: &IVec2) -> Vec<String> {
+    bfs_all(
         start,
-        |p| {
-            DIRECTIONS
-                .iter()
-                .filter_map(|dir| {
-                    let next_pos = p + dir;
-                    if keys.contains(&&next_pos) {
-                        Some((next_pos, 1))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<(IVec2, i32)>>()
-        },
+        |&p| map.neighbours4(p).filter(|&next_pos| map[next_pos] != '.'),
         |p| p == end,
-        5,
-    );
-    let shortest_path_weight = k_paths
-        .iter()
-        .map(|(_, len)| len)
-        .min()
-        .expect("At least one path");
-
-    k_paths
-        .iter()
-        .filter(|(_, l)| l == shortest_path_weight)
-        .map(|(p, _)| {
-            p.iter()
-                .tuple_windows()
-                .filter_map(|(a, b)| match b - a {
-                    s if s == DIRECTIONS[0] => Some('>'),
-                    s if s == DIRECTIONS[1] => Some('<'),
-                    s if s == DIRECTIONS[2] => Some('v'),
-                    s if s == DIRECTIONS[3] => Some('^'),
-                    _ => None,
-                })
-                .collect::<String>()
-        })
-        .collect::<Vec<String>>()
+    )
+    .expect("every key should be reachable")
+    .paths()
+    .iter()
+    .map(|p| {
+        p.iter()
+            .tuple_windows()
+            .filter_map(|(a, b)| Direction::from_ivec2(b - a).and_then(Direction::to_arrow))
+            .collect::<String>()
+    })
+    .sorted()
+    .collect::<Vec<String>>()
 }
 
 /// Find the path for a code on the keypad.
@@ -158,7 +131,7 @@ fn find_key_pad_paths(code: &str) -> Vec<String> {
         .multi_cartesian_product()
         .map(|p| {
             let mut path = p.join("A");
-            path.push_str("A");
+            path.push('A');
             path
         })
         .collect()
@@ -179,7 +152,7 @@ fn find_dir_pad_paths(path: &str) -> Vec<String> {
         .multi_cartesian_product()
         .map(|p| {
             let mut path = p.join("A");
-            path.push_str("A");
+            path.push('A');
             path
         })
         .collect()
@@ -187,51 +160,37 @@ fn find_dir_pad_paths(path: &str) -> Vec<String> {
 
 /// Calculate the code complexity.
 fn calc_complexity(code: &str, key_presses: String) -> miette::Result<i32> {
-    let num = code
-        .strip_suffix("A")
-        .expect("AoC code should end in 'A'")
-        .parse::<i32>()
-        .map_err(|e| miette!("Could not parse number {}", e))?;
+    Ok(code_number(code)? as i32 * key_presses.len() as i32)
+}
 
-    Ok(num * key_presses.len() as i32)
+/// The numeric part of a code, e.g. 29 for `029A`.
+pub(crate) fn code_number(code: &str) -> miette::Result<u64> {
+    let digits = code
+        .strip_suffix('A')
+        .filter(|d| !d.is_empty() && d.bytes().all(|b| b.is_ascii_digit()))
+        .ok_or_else(|| miette!("code {code:?} should be digits followed by 'A'"))?;
+    digits
+        .parse::<u64>()
+        .map_err(|e| miette!("Could not parse number {}", e))
 }
 
-type Map = HashMap<IVec2, char>;
+pub(crate) type Map = Grid<char>;
 
-fn parse_key_pad() -> Map {
+/// Keypads have a gap, marked `.`, that the robot arms must never point at.
+pub(crate) fn parse_key_pad() -> Map {
     let input = "789
 456
 123
 .0A";
 
-    let mut map = HashMap::new();
-    for (y, l) in input.lines().enumerate() {
-        for (x, c) in l.chars().enumerate() {
-            if c == '.' {
-                continue;
-            }
-            let coord = IVec2::new(x as i32, y as i32);
-            map.insert(coord, c);
-        }
-    }
-    map
+    Grid::parse(input).expect("keypad layout should be a valid grid")
 }
 
-fn parse_dir_pad() -> Map {
+pub(crate) fn parse_dir_pad() -> Map {
     let input = ".^A
 <v>";
 
-    let mut map = HashMap::new();
-    for (y, l) in input.lines().enumerate() {
-        for (x, c) in l.chars().enumerate() {
-            if c == '.' {
-                continue;
-            }
-            let coord = IVec2::new(x as i32, y as i32);
-            map.insert(coord, c);
-        }
-    }
-    map
+    Grid::parse(input).expect("direction pad layout should be a valid grid")
 }
 
 #[cfg(test)]
@@ -293,4 +252,14 @@ mod tests {
         assert_eq!(complexity, calc_complexity(code, key_presses.to_string())?);
         Ok(())
     }
+
+    #[rstest]
+    #[case("029")]
+    #[case("A")]
+    #[case("+29A")]
+    #[case("0B9A")]
+    fn test_bad_code(#[case] code: &str) {
+        assert!(code_number(code).is_err());
+        assert!(process(code).is_err());
+    }
 }
@@ -1,17 +1,139 @@
+use std::collections::HashMap;
+
+use aoc_answer::Answer;
+use itertools::Itertools;
+use miette::miette;
+
+use crate::part1::{
+    code_number, find_position, find_shortest_paths, parse_dir_pad, parse_key_pad, Map,
+};
+
+/// Robots using directional keypads between me and the robot at the door.
+pub const ROBOTS: usize = 25;
+
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String> {
-    todo!("day21 - part 2");
+pub fn process(input: &str, robots: usize) -> miette::Result<Answer> {
+    let mut presses = Presses::new();
+    let mut total = 0;
+    for code in input.lines().map(str::trim).filter(|code| !code.is_empty()) {
+        total += code_number(code)? * presses.code(code, robots)?;
+    }
+    Ok(total.into())
+}
+
+/// Counts the fewest buttons I have to press, memoising the cost of every move on a directional
+/// keypad by how many robots are left between it and me.
+struct Presses {
+    key_pad: Map,
+    dir_pad: Map,
+    memo: HashMap<(char, char, usize), u64>,
+}
+
+impl Presses {
+    fn new() -> Self {
+        Self {
+            key_pad: parse_key_pad(),
+            dir_pad: parse_dir_pad(),
+            memo: HashMap::new(),
+        }
+    }
+
+    /// Presses to type `code` on the door keypad through `robots` directional keypads.
+    fn code(&mut self, code: &str, robots: usize) -> miette::Result<u64> {
+        let mut total = 0;
+        for (from, to) in "A".chars().chain(code.chars()).tuple_windows() {
+            let paths = moves(&self.key_pad, from, to)?;
+            let mut best = u64::MAX;
+            for path in paths {
+                best = best.min(self.sequence(&path, robots)?);
+            }
+            total += best;
+        }
+        Ok(total)
+    }
+
+    /// Presses to type `sequence` on a directional keypad that is `depth` robots away from me.
+    fn sequence(&mut self, sequence: &str, depth: usize) -> miette::Result<u64> {
+        if depth == 0 {
+            return Ok(sequence.len() as u64);
+        }
+        let mut total = 0;
+        for (from, to) in "A".chars().chain(sequence.chars()).tuple_windows() {
+            total += self.step(from, to, depth)?;
+        }
+        Ok(total)
+    }
+
+    /// Presses to move a directional keypad arm from `from` to `to` and press it.
+    fn step(&mut self, from: char, to: char, depth: usize) -> miette::Result<u64> {
+        if let Some(&presses) = self.memo.get(&(from, to, depth)) {
+            return Ok(presses);
+        }
+        let mut best = u64::MAX;
+        for path in moves(&self.dir_pad, from, to)? {
+            best = best.min(self.sequence(&path, depth - 1)?);
+        }
+        self.memo.insert((from, to, depth), best);
+        Ok(best)
+    }
+}
+
+/// Every shortest way to move from `from` to `to` on `map` and press the key, e.g. `<^A`.
+fn moves(map: &Map, from: char, to: char) -> miette::Result<Vec<String>> {
+    let start = find_position(map, &from).ok_or(miette!("{from:?} is not on the keypad"))?;
+    let end = find_position(map, &to).ok_or(miette!("{to:?} is not on the keypad"))?;
+    Ok(find_shortest_paths(map, &start, &end)
+        .into_iter()
+        .map(|mut path| {
+            path.push('A');
+            path
+        })
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "029A
+980A
+179A
+456A
+379A";
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        assert_eq!("154115708116294", process(INPUT, ROBOTS)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_blank_lines() -> miette::Result<()> {
+        let input = format!("\n{INPUT}\n\n");
+        assert_eq!(process(INPUT, ROBOTS)?, process(&input, ROBOTS)?);
+        assert!(process("029A\n98A0", ROBOTS).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_part1() -> miette::Result<()> {
+        assert_eq!(crate::part1::process(INPUT)?, process(INPUT, 2)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("029A", 0, 12)]
+    #[case("029A", 1, 28)]
+    #[case("029A", 2, 68)]
+    #[case("980A", 2, 60)]
+    #[case("179A", 2, 68)]
+    fn test_code(
+        #[case] code: &str,
+        #[case] robots: usize,
+        #[case] expected: u64,
+    ) -> miette::Result<()> {
+        assert_eq!(expected, Presses::new().code(code, robots)?);
         Ok(())
     }
 }
@@ -1,12 +1,13 @@
-use day22::part2::process;
+use day22::{part2::best_sequence, secret::parse};
 use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../../../input_day22.txt");
-    let result = process(file).context("process part 2")?;
-    println!("Part 2: {}", result);
+    let file = aoc_input::load_cli(22)?;
+    let best = best_sequence(&parse(&file).context("process part 2")?);
+    println!("Part 2: {}", best.bananas);
+    println!("Sequence: {}", best);
     Ok(())
 }
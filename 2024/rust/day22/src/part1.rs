@@ -1,44 +1,19 @@
-use miette::miette;
+use aoc_answer::Answer;
+
+use crate::secret::{parse, Secrets};
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
+pub fn process(input: &str) -> miette::Result<Answer> {
     let parsed = parse(input)?;
     let res = parsed.iter().map(|&seed| simulate_2k(seed)).sum::<i64>();
 
-    Ok(res.to_string())
-}
-
-fn parse(input: &str) -> miette::Result<Vec<i64>> {
-    input
-        .lines()
-        .map(|l| {
-            l.parse::<i64>()
-                .map_err(|e| miette!("could not parse number {}", e))
-        })
-        .collect()
+    Ok(res.into())
 }
 
 fn simulate_2k(seed: i64) -> i64 {
-    let mut curr = seed;
-    for _ in 0..2000 {
-        curr = next_number(curr);
-    }
-    curr
-}
-
-fn next_number(curr: i64) -> i64 {
-    let mut curr = curr;
-
-    curr ^= curr * 64;
-    curr %= 16777216;
-
-    curr ^= curr / 32;
-    curr %= 16777216;
-
-    curr ^= curr * 2048;
-    curr %= 16777216;
-
-    curr
+    Secrets::new(seed)
+        .nth(1999)
+        .expect("secret numbers should never run out")
 }
 
 #[cfg(test)]
@@ -65,20 +40,4 @@ mod tests {
         assert_eq!(end, simulate_2k(seed));
         Ok(())
     }
-
-    #[rstest]
-    #[case(123, 15887950)]
-    #[case(15887950, 16495136)]
-    #[case(16495136, 527345)]
-    #[case(527345, 704524)]
-    #[case(704524, 1553684)]
-    #[case(1553684, 12683156)]
-    #[case(12683156, 11100544)]
-    #[case(11100544, 12249484)]
-    #[case(12249484, 7753432)]
-    #[case(7753432, 5908254)]
-    fn test_next_number(#[case] seed: i64, #[case] res: i64) -> miette::Result<()> {
-        assert_eq!(res, next_number(seed));
-        Ok(())
-    }
 }
@@ -1,6 +1,79 @@
+use std::fmt;
+
+use aoc_answer::Answer;
+use itertools::Itertools;
+
+use crate::secret::{parse, Secrets};
+
+/// Price changes are between -9 and 9, so a window of four fits in `19^4` slots.
+const WINDOWS: usize = 19 * 19 * 19 * 19;
+
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String> {
-    todo!("day22 - part 2");
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let best = best_sequence(&parse(input)?);
+    tracing::info!(%best, "best sequence");
+    Ok(best.bananas.into())
+}
+
+/// The four price changes the monkey should wait for, and how many bananas that earns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Best {
+    pub changes: [i8; 4],
+    pub bananas: u32,
+}
+
+impl fmt::Display for Best {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} for {} bananas",
+            self.changes.iter().join(","),
+            self.bananas
+        )
+    }
+}
+
+/// Find the sequence of four price changes that earns the most bananas over every buyer's first
+/// 2000 prices, where each buyer sells the first time the sequence appears.
+pub fn best_sequence(seeds: &[i64]) -> Best {
+    let mut bananas = vec![0u32; WINDOWS];
+    // The last buyer (plus one) to have seen each window, so only their first sale counts.
+    let mut seen = vec![0usize; WINDOWS];
+
+    for (buyer, &seed) in seeds.iter().enumerate() {
+        let prices = std::iter::once(seed)
+            .chain(Secrets::new(seed).take(2000))
+            .map(|secret| (secret % 10) as i8);
+        let mut window = 0;
+        for (i, (prev, price)) in prices.tuple_windows().enumerate() {
+            window = (window * 19 + (price - prev + 9) as usize) % WINDOWS;
+            if i >= 3 && seen[window] != buyer + 1 {
+                seen[window] = buyer + 1;
+                bananas[window] += price as u32;
+            }
+        }
+    }
+
+    let (window, &bananas) = bananas
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, b)| b)
+        .expect("there should be at least one window");
+    Best {
+        changes: changes(window),
+        bananas,
+    }
+}
+
+/// The price changes encoded in a window index, oldest first.
+fn changes(window: usize) -> [i8; 4] {
+    let mut changes = [0; 4];
+    let mut rest = window;
+    for change in changes.iter_mut().rev() {
+        *change = (rest % 19) as i8 - 9;
+        rest /= 19;
+    }
+    changes
 }
 
 #[cfg(test)]
@@ -9,9 +82,32 @@ mod tests {
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        let input = "1
+2
+3
+2024";
+        assert_eq!("23", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_best_sequence() {
+        assert_eq!(
+            Best {
+                changes: [-2, 1, -1, 3],
+                bananas: 23
+            },
+            best_sequence(&[1, 2, 3, 2024])
+        );
+    }
+
+    #[test]
+    fn test_changes() {
+        let window = [-2i8, 1, -1, 3]
+            .iter()
+            .fold(0, |w, &c| w * 19 + (c + 9) as usize);
+        assert_eq!([-2, 1, -1, 3], changes(window));
+        assert_eq!([-9; 4], changes(0));
+        assert_eq!([9; 4], changes(WINDOWS - 1));
+    }
 }
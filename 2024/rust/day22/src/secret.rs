@@ -0,0 +1,81 @@
+use miette::miette;
+
+/// Each buyer's secret number is pruned to 24 bits.
+const PRUNE: i64 = 16777216;
+
+/// The secret numbers a buyer generates after `seed`, in order.
+#[derive(Debug, Clone)]
+pub struct Secrets {
+    curr: i64,
+}
+
+impl Secrets {
+    pub fn new(seed: i64) -> Self {
+        Self { curr: seed }
+    }
+}
+
+impl Iterator for Secrets {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        self.curr = next_number(self.curr);
+        Some(self.curr)
+    }
+}
+
+pub fn next_number(curr: i64) -> i64 {
+    let mut curr = curr;
+
+    curr ^= curr * 64;
+    curr %= PRUNE;
+
+    curr ^= curr / 32;
+    curr %= PRUNE;
+
+    curr ^= curr * 2048;
+    curr %= PRUNE;
+
+    curr
+}
+
+/// One initial secret number per line.
+pub fn parse(input: &str) -> miette::Result<Vec<i64>> {
+    input
+        .lines()
+        .map(|l| {
+            l.parse::<i64>()
+                .map_err(|e| miette!("could not parse number {}", e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(123, 15887950)]
+    #[case(15887950, 16495136)]
+    #[case(16495136, 527345)]
+    #[case(527345, 704524)]
+    #[case(704524, 1553684)]
+    #[case(1553684, 12683156)]
+    #[case(12683156, 11100544)]
+    #[case(11100544, 12249484)]
+    #[case(12249484, 7753432)]
+    #[case(7753432, 5908254)]
+    fn test_next_number(#[case] seed: i64, #[case] res: i64) -> miette::Result<()> {
+        assert_eq!(res, next_number(seed));
+        Ok(())
+    }
+
+    #[test]
+    fn test_secrets() {
+        assert_eq!(
+            vec![15887950, 16495136, 527345],
+            Secrets::new(123).take(3).collect::<Vec<_>>()
+        );
+    }
+}
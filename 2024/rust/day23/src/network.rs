@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use aoc_parse::ParseError;
+use winnow::{
+    ascii::{alpha1, line_ending},
+    combinator::{separated, separated_pair},
+};
+
+/// The LAN party as an undirected graph, with computers numbered in the order they first appear.
+#[derive(Debug, Clone)]
+pub struct Network<'a> {
+    names: Vec<&'a str>,
+    /// The sorted neighbours of every computer.
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl<'a> Network<'a> {
+    /// Build the network from `a-b` connections, one per line.
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let links: Vec<(&str, &str)> = aoc_parse::parse(
+            input,
+            separated(1.., separated_pair(alpha1, '-', alpha1), line_ending),
+        )?;
+
+        let mut ids = HashMap::new();
+        let mut network = Self {
+            names: vec![],
+            adjacency: vec![],
+        };
+        for (a, b) in links {
+            let a = network.id(&mut ids, a);
+            let b = network.id(&mut ids, b);
+            if a != b {
+                network.adjacency[a].push(b);
+                network.adjacency[b].push(a);
+            }
+        }
+        for neighbours in &mut network.adjacency {
+            neighbours.sort_unstable();
+            neighbours.dedup();
+        }
+        Ok(network)
+    }
+
+    fn id(&mut self, ids: &mut HashMap<&'a str, usize>, name: &'a str) -> usize {
+        *ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            self.adjacency.push(vec![]);
+            self.names.len() - 1
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, id: usize) -> &'a str {
+        self.names[id]
+    }
+
+    pub fn neighbours(&self, id: usize) -> &[usize] {
+        &self.adjacency[id]
+    }
+
+    pub fn is_connected(&self, a: usize, b: usize) -> bool {
+        self.adjacency[a].binary_search(&b).is_ok()
+    }
+
+    /// Every set of three inter-connected computers, each listed once in increasing id order.
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        let mut triangles = vec![];
+        for a in 0..self.len() {
+            let neighbours = self.neighbours(a);
+            // Only look at later neighbours so every triangle is found from its smallest id.
+            let later = &neighbours[neighbours.partition_point(|&n| n < a)..];
+            for (i, &b) in later.iter().enumerate() {
+                for &c in &later[i + 1..] {
+                    if self.is_connected(b, c) {
+                        triangles.push([a, b, c]);
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// The largest set of computers that are all connected to each other.
+    pub fn max_clique(&self) -> Vec<usize> {
+        let mut best = vec![];
+        self.bron_kerbosch(&mut vec![], (0..self.len()).collect(), vec![], &mut best);
+        best
+    }
+
+    /// Bron–Kerbosch with pivoting: grow the clique `r` from the candidates `p`, skipping
+    /// neighbours of the pivot since any clique containing them can be found through the pivot,
+    /// and using `x` to avoid reporting the same maximal clique twice.
+    fn bron_kerbosch(
+        &self,
+        r: &mut Vec<usize>,
+        mut p: Vec<usize>,
+        mut x: Vec<usize>,
+        best: &mut Vec<usize>,
+    ) {
+        if p.is_empty() {
+            if x.is_empty() && r.len() > best.len() {
+                best.clone_from(r);
+            }
+            return;
+        }
+        // A branch can never beat the best clique if it cannot grow past it.
+        if r.len() + p.len() <= best.len() {
+            return;
+        }
+
+        let pivot = p
+            .iter()
+            .chain(&x)
+            .copied()
+            .max_by_key(|&u| p.iter().filter(|&&v| self.is_connected(u, v)).count())
+            .expect("p is not empty");
+        let candidates = p
+            .iter()
+            .copied()
+            .filter(|&v| !self.is_connected(pivot, v))
+            .collect::<Vec<_>>();
+
+        for v in candidates {
+            r.push(v);
+            self.bron_kerbosch(
+                r,
+                p.iter()
+                    .copied()
+                    .filter(|&u| self.is_connected(v, u))
+                    .collect(),
+                x.iter()
+                    .copied()
+                    .filter(|&u| self.is_connected(v, u))
+                    .collect(),
+                best,
+            );
+            r.pop();
+            p.retain(|&u| u != v);
+            x.push(v);
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) const EXAMPLE: &str = "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let network = Network::parse(EXAMPLE)?;
+        assert_eq!(16, network.len());
+        assert_eq!("kh", network.name(0));
+        assert!(network.is_connected(0, 1));
+        assert!(network.is_connected(1, 0));
+        assert!(network.neighbours(0).is_sorted());
+        Ok(())
+    }
+
+    #[test]
+    fn test_triangles() -> miette::Result<()> {
+        let network = Network::parse(EXAMPLE)?;
+        let triangles = network
+            .triangles()
+            .into_iter()
+            .map(|t| t.map(|id| network.name(id)).iter().sorted().join(","))
+            .collect::<Vec<_>>();
+        assert_eq!(12, triangles.len());
+        assert!(triangles.contains(&"co,de,ta".to_string()));
+        assert!(triangles.contains(&"td,wh,yn".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_clique() -> miette::Result<()> {
+        let network = Network::parse("a-b\nb-c\nc-a\nc-d\nd-e")?;
+        let clique = network.max_clique();
+        assert_eq!(
+            vec!["a", "b", "c"],
+            clique
+                .iter()
+                .map(|&id| network.name(id))
+                .sorted()
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+}
@@ -1,17 +1,30 @@
+use aoc_answer::Answer;
+use itertools::Itertools;
+
+use crate::network::Network;
+
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String> {
-    todo!("day23 - part 2");
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let network = Network::parse(input)?;
+
+    let password = network
+        .max_clique()
+        .into_iter()
+        .map(|id| network.name(id))
+        .sorted()
+        .join(",");
+
+    Ok(password.into())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::network::EXAMPLE;
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        assert_eq!("co,de,ka,ta", process(EXAMPLE)?);
         Ok(())
     }
 }
@@ -0,0 +1,42 @@
+use std::{fs, path::PathBuf};
+
+use aoc_input::Source;
+use clap::Parser;
+use day24::{circuit::parse, dot::to_dot, part2::swapped_wires};
+use miette::{Context, IntoDiagnostic};
+
+/// Export the day 24 circuit as a Graphviz DOT graph.
+#[derive(Debug, Parser)]
+#[command(name = "dot")]
+struct Cli {
+    /// Read the circuit from this file (or `-` for stdin) instead of `input_day24.txt`.
+    input: Option<PathBuf>,
+    /// Write the graph to this file.
+    #[arg(short, long)]
+    output: PathBuf,
+    /// Highlight the wires that break the adder pattern.
+    #[arg(short, long)]
+    suspicious: bool,
+}
+
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+    let cli = Cli::parse();
+
+    let source = match cli.input {
+        Some(path) => Source::from_arg(path),
+        None => Source::for_day(24),
+    };
+    let file = source.read()?;
+    let (_, wires) = parse(&file).context("parse circuit")?;
+
+    let suspicious = if cli.suspicious {
+        swapped_wires(&wires)
+    } else {
+        vec![]
+    };
+    fs::write(&cli.output, to_dot(&wires, &suspicious))
+        .into_diagnostic()
+        .with_context(|| format!("write {}", cli.output.display()))?;
+    Ok(())
+}
@@ -0,0 +1,377 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_parse::ParseError;
+use itertools::Itertools;
+use miette::Diagnostic;
+use thiserror::Error;
+use winnow::{
+    ascii::{alphanumeric1, dec_uint, line_ending, multispace1, space1},
+    combinator::{alt, cut_err, separated, separated_pair, seq},
+    Parser,
+};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    AND,
+    OR,
+    XOR,
+}
+
+impl Operation {
+    pub fn apply(self, lhs: u8, rhs: u8) -> u8 {
+        match self {
+            Operation::AND => lhs & rhs,
+            Operation::OR => lhs | rhs,
+            Operation::XOR => lhs ^ rhs,
+        }
+    }
+}
+
+/// A gate, named after the wire it drives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wire {
+    pub lhs: String,
+    pub op: Operation,
+    pub rhs: String,
+}
+
+impl Wire {
+    pub fn new(lhs: String, op: Operation, rhs: String) -> Self {
+        Wire { lhs, op, rhs }
+    }
+}
+
+pub type Values = HashMap<String, u8>;
+pub type Wires = HashMap<String, Wire>;
+
+pub fn parse(input: &str) -> Result<(Values, Wires), ParseError> {
+    aoc_parse::parse(
+        input,
+        seq!(
+            parse_initial_values,
+            _: multispace1,
+            parse_wires
+        ),
+    )
+}
+
+fn parse_initial_values(input: &mut &str) -> winnow::PResult<HashMap<String, u8>> {
+    separated(
+        1..,
+        separated_pair(alphanumeric1.parse_to(), ": ", dec_uint::<_, u8, _>),
+        line_ending,
+    )
+    .parse_next(input)
+}
+
+fn parse_wires(input: &mut &str) -> winnow::PResult<HashMap<String, Wire>> {
+    // ntg XOR fgs -> mjb
+    separated(
+        1..,
+        seq!(
+            alphanumeric1,
+            // Past the first wire this can only be a gate, so report where it goes wrong.
+            _: space1,
+            cut_err(parse_operation),
+            _: cut_err(space1),
+            cut_err(alphanumeric1),
+            _: cut_err(" -> "),
+            cut_err(alphanumeric1),
+        )
+        .map(|(lhs, op, rhs, out): (&str, _, &str, &str)| {
+            (
+                out.to_string(),
+                Wire::new(lhs.to_string(), op, rhs.to_string()),
+            )
+        }),
+        line_ending,
+    )
+    .parse_next(input)
+}
+
+fn parse_operation(input: &mut &str) -> winnow::PResult<Operation> {
+    alt((
+        "AND".value(Operation::AND),
+        "OR".value(Operation::OR),
+        "XOR".value(Operation::XOR),
+    ))
+    .parse_next(input)
+}
+
+/// The gates of a circuit sorted so every gate comes after the gates driving its inputs.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    names: Vec<String>,
+    /// Wires no gate drives, which need a value before the circuit can be evaluated.
+    inputs: Vec<usize>,
+    /// `(output, lhs, op, rhs)` in evaluation order.
+    gates: Vec<(usize, usize, Operation, usize)>,
+}
+
+impl Circuit {
+    pub fn new(wires: &Wires) -> Result<Self, CircuitError> {
+        let names = wires
+            .iter()
+            .flat_map(|(out, wire)| [out, &wire.lhs, &wire.rhs])
+            .unique()
+            .sorted()
+            .cloned()
+            .collect::<Vec<_>>();
+        let id = |name: &str| {
+            names
+                .binary_search_by(|n| n.as_str().cmp(name))
+                .expect("every wire should be named")
+        };
+
+        // Kahn's algorithm: a gate is ready once every gate driving its inputs has been placed.
+        let mut pending = vec![0; names.len()];
+        let mut consumers = vec![vec![]; names.len()];
+        for (out, wire) in wires {
+            for input in [&wire.lhs, &wire.rhs] {
+                if wires.contains_key(input) {
+                    pending[id(out)] += 1;
+                    consumers[id(input)].push(id(out));
+                }
+            }
+        }
+
+        let inputs = (0..names.len())
+            .filter(|&i| !wires.contains_key(&names[i]))
+            .collect::<Vec<_>>();
+        let mut ready = (0..names.len())
+            .filter(|&i| wires.contains_key(&names[i]) && pending[i] == 0)
+            .collect::<VecDeque<_>>();
+        let mut gates = Vec::with_capacity(wires.len());
+        while let Some(out) = ready.pop_front() {
+            let wire = &wires[&names[out]];
+            gates.push((out, id(&wire.lhs), wire.op, id(&wire.rhs)));
+            for &next in &consumers[out] {
+                pending[next] -= 1;
+                if pending[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+
+        if gates.len() < wires.len() {
+            // Whatever could not be placed is on, or downstream of, a cycle.
+            let stuck = (0..names.len())
+                .filter(|&i| pending[i] > 0)
+                .map(|i| names[i].clone())
+                .collect();
+            return Err(CircuitError::Cycle { wires: stuck });
+        }
+        Ok(Self {
+            names,
+            inputs,
+            gates,
+        })
+    }
+
+    /// The value of every wire, given a value for every input.
+    pub fn evaluate(&self, values: &Values) -> Result<Values, CircuitError> {
+        let mut state = vec![0; self.names.len()];
+        for &input in &self.inputs {
+            let name = &self.names[input];
+            state[input] = *values
+                .get(name)
+                .ok_or_else(|| CircuitError::Undefined { wire: name.clone() })?;
+        }
+        for &(out, lhs, op, rhs) in &self.gates {
+            state[out] = op.apply(state[lhs], state[rhs]);
+        }
+        Ok(self.names.iter().cloned().zip(state).collect())
+    }
+
+    /// The number on the `z` wires, given a value for every input.
+    pub fn output(&self, values: &Values) -> Result<u64, CircuitError> {
+        Ok(bits_of(&self.evaluate(values)?, 'z'))
+    }
+
+    /// Run the circuit with the numbers `x` and `y` on the `x` and `y` input wires.
+    pub fn add(&self, x: u64, y: u64) -> Result<u64, CircuitError> {
+        let values = self
+            .inputs
+            .iter()
+            .map(|&i| &self.names[i])
+            .filter_map(|name| {
+                let number = match name.chars().next()? {
+                    'x' => x,
+                    'y' => y,
+                    _ => return None,
+                };
+                let bit = name[1..].parse::<u32>().ok()?;
+                Some((name.clone(), number.checked_shr(bit).unwrap_or(0) as u8 & 1))
+            })
+            .collect();
+        self.output(&values)
+    }
+}
+
+/// The number formed by the wires named `prefix` followed by their bit index.
+fn bits_of(values: &Values, prefix: char) -> u64 {
+    values
+        .iter()
+        .filter_map(|(name, &value)| {
+            let bit = name.strip_prefix(prefix)?.parse::<u32>().ok()?;
+            Some((value as u64) << bit)
+        })
+        .fold(0, |acc, bit| acc | bit)
+}
+
+#[derive(Debug, Error, Diagnostic, PartialEq, Eq)]
+pub enum CircuitError {
+    #[error("the circuit has a cycle through {}", wires.join(", "))]
+    #[diagnostic(
+        code(day24::circuit::cycle),
+        help("every gate output should only feed gates further along the circuit")
+    )]
+    Cycle { wires: Vec<String> },
+
+    #[error("wire {wire} is not driven by a gate and has no initial value")]
+    #[diagnostic(code(day24::circuit::undefined))]
+    Undefined { wire: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part2::tests::{adder, swap};
+    use rstest::rstest;
+
+    fn circuit(input: &str) -> miette::Result<Circuit> {
+        let (_, wires) = parse(input)?;
+        Ok(Circuit::new(&wires)?)
+    }
+
+    #[test]
+    fn test_evaluate() -> miette::Result<()> {
+        let input = "x00: 1
+x01: 1
+y00: 0
+y01: 1
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+z00 OR z01 -> z02";
+        let (values, wires) = parse(input)?;
+        let circuit = Circuit::new(&wires)?;
+        let state = circuit.evaluate(&values)?;
+        assert_eq!(Some(&0), state.get("z01"));
+        assert_eq!(0, circuit.output(&values)?);
+        assert_eq!(0b111, circuit.add(0b01, 0b11)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 1)]
+    #[case(0b1011_0110, 0b0110_1101)]
+    #[case(255, 255)]
+    fn test_add(#[case] x: u64, #[case] y: u64) -> miette::Result<()> {
+        assert_eq!(x + y, circuit(&adder(8))?.add(x, y)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_swapped() -> miette::Result<()> {
+        let circuit = circuit(&swap(&adder(8), "z03", "c03"))?;
+        assert_ne!(0b1000 + 0b1000, circuit.add(0b1000, 0b1000)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cycle() -> miette::Result<()> {
+        let (_, wires) = parse(
+            "x00: 1
+
+x00 AND abc -> def
+def OR x00 -> abc
+def XOR x00 -> z00",
+        )?;
+        assert_eq!(
+            Err(CircuitError::Cycle {
+                wires: vec!["abc".to_string(), "def".to_string(), "z00".to_string()]
+            }),
+            Circuit::new(&wires).map(|_| ())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_undefined() -> miette::Result<()> {
+        let (values, wires) = parse(
+            "x00: 1
+
+x00 AND y00 -> z00",
+        )?;
+        assert_eq!(
+            Err(CircuitError::Undefined {
+                wire: "y00".to_string()
+            }),
+            Circuit::new(&wires)?.evaluate(&values)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_init() -> miette::Result<()> {
+        let mut input = "x00: 1\nx01: 0";
+        let expected = HashMap::from_iter(vec![("x00".to_string(), 1), ("x01".to_string(), 0)]);
+        assert_eq!(expected, parse_initial_values(&mut input).unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_wire() -> miette::Result<()> {
+        let mut input = "ntg XOR fgs -> mjb\ny02 OR x01 -> tnw";
+        let expected = HashMap::from_iter(vec![
+            (
+                "mjb".to_string(),
+                Wire::new("ntg".to_string(), Operation::XOR, "fgs".to_string()),
+            ),
+            (
+                "tnw".to_string(),
+                Wire::new("y02".to_string(), Operation::OR, "x01".to_string()),
+            ),
+        ]);
+        assert_eq!(expected, parse_wires(&mut input).unwrap());
+        Ok(())
+    }
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let input = "x00: 1
+x01: 0
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw";
+        let expected = (
+            HashMap::from_iter(vec![("x00".to_string(), 1), ("x01".to_string(), 0)]),
+            HashMap::from_iter(vec![
+                (
+                    "mjb".to_string(),
+                    Wire::new("ntg".to_string(), Operation::XOR, "fgs".to_string()),
+                ),
+                (
+                    "tnw".to_string(),
+                    Wire::new("y02".to_string(), Operation::OR, "x01".to_string()),
+                ),
+            ]),
+        );
+        assert_eq!(expected, parse(input)?);
+        assert_eq!(expected, parse(&format!("{input}\n"))?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(
+        "x00: 1\n\nx00 AND y00 -> z00\nx00 NAND y00 -> z01",
+        "x00: 1\n\nx00 AND y00 -> z00\nx00 "
+    )]
+    #[case("x00: 1\n\nx00 AND -> z00", "x00: 1\n\nx00 AND ")]
+    fn test_parse_errors(#[case] input: &str, #[case] before: &str) {
+        let err = parse(input).unwrap_err();
+        assert_eq!(before.len(), err.offset());
+    }
+}
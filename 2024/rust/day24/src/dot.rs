@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use crate::circuit::{Operation, Wires};
+
+/// Render the circuit as a Graphviz DOT graph.
+///
+/// Every gate is a box coloured by its operation, wired to the wires it reads and drives. The
+/// `x` and `y` inputs are lined up at the top and the `z` outputs at the bottom, both in bit
+/// order, and the `suspicious` wires are drawn in red.
+pub fn to_dot(wires: &Wires, suspicious: &[String]) -> String {
+    let mut dot = String::new();
+    let mut line = |s: String| writeln!(dot, "{s}").expect("writing to a String cannot fail");
+
+    line("digraph circuit {".to_string());
+    line("    rankdir=TB;".to_string());
+    line("    node [shape=ellipse, fontname=monospace];".to_string());
+
+    let names = wires
+        .iter()
+        .flat_map(|(out, wire)| [out, &wire.lhs, &wire.rhs])
+        .unique()
+        .sorted()
+        .collect::<Vec<_>>();
+    for name in &names {
+        if suspicious.contains(name) {
+            line(format!(
+                "    {name} [color=red, fontcolor=red, penwidth=3];"
+            ));
+        }
+    }
+
+    for (out, wire) in wires.iter().sorted_by_key(|(out, _)| *out) {
+        let gate = format!("gate_{out}");
+        let (label, colour) = match wire.op {
+            Operation::AND => ("AND", "lightblue"),
+            Operation::OR => ("OR", "palegreen"),
+            Operation::XOR => ("XOR", "gold"),
+        };
+        line(format!(
+            "    {gate} [label={label}, shape=box, style=filled, fillcolor={colour}];"
+        ));
+        line(format!("    {} -> {gate};", wire.lhs));
+        line(format!("    {} -> {gate};", wire.rhs));
+        line(format!("    {gate} -> {out};"));
+    }
+
+    // Keep the inputs and outputs in bit order, x00 y00 x01 y01 ... and z00 z01 ...
+    let inputs = names
+        .iter()
+        .filter(|n| n.starts_with('x') || n.starts_with('y'))
+        .sorted_by_key(|n| (&n[1..], &n[..1]))
+        .collect::<Vec<_>>();
+    let outputs = names
+        .iter()
+        .filter(|n| n.starts_with('z'))
+        .collect::<Vec<_>>();
+    for (rank, group) in [("source", inputs), ("sink", outputs)] {
+        if group.is_empty() {
+            continue;
+        }
+        line(format!(
+            "    {{ rank={rank}; {}; }}",
+            group.iter().join("; ")
+        ));
+        if group.len() > 1 {
+            line(format!("    {} [style=invis];", group.iter().join(" -> ")));
+        }
+    }
+
+    line("}".to_string());
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::parse;
+
+    #[test]
+    fn test_to_dot() -> miette::Result<()> {
+        let (_, wires) = parse(
+            "x00: 1
+y00: 0
+
+x00 XOR y00 -> z00
+y00 AND x00 -> z01",
+        )?;
+        assert_eq!(
+            "digraph circuit {
+    rankdir=TB;
+    node [shape=ellipse, fontname=monospace];
+    z01 [color=red, fontcolor=red, penwidth=3];
+    gate_z00 [label=XOR, shape=box, style=filled, fillcolor=gold];
+    x00 -> gate_z00;
+    y00 -> gate_z00;
+    gate_z00 -> z00;
+    gate_z01 [label=AND, shape=box, style=filled, fillcolor=lightblue];
+    y00 -> gate_z01;
+    x00 -> gate_z01;
+    gate_z01 -> z01;
+    { rank=source; x00; y00; }
+    x00 -> y00 [style=invis];
+    { rank=sink; z00; z01; }
+    z00 -> z01 [style=invis];
+}
+",
+            to_dot(&wires, &["z01".to_string()])
+        );
+        Ok(())
+    }
+}
@@ -1,2 +1,4 @@
+pub mod circuit;
+pub mod dot;
 pub mod part1;
 pub mod part2;
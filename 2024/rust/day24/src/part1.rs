@@ -1,124 +1,12 @@
-use itertools::Itertools;
-use miette::miette;
-use std::{
-    collections::HashMap,
-    ops::{BitAnd, BitOr, BitXor},
-};
+use aoc_answer::Answer;
 
-use winnow::{
-    ascii::{alphanumeric1, dec_uint, line_ending, multispace1, space1},
-    combinator::{repeat, separated, separated_pair, seq},
-    token::take_while,
-    Parser,
-};
+use crate::circuit::{parse, Circuit};
 
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let (mut initial, wires) =
-        parse(input).map_err(|e| miette!("could not parse AoC input {}", e))?;
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let (initial, wires) = parse(input)?;
+    let res = Circuit::new(&wires)?.output(&initial)?;
 
-    let zwires = wires
-        .keys()
-        .cloned()
-        .filter(|k| k.starts_with("z"))
-        .sorted()
-        .collect::<Vec<_>>();
-
-    loop {
-        if zwires.iter().all(|z| initial.contains_key(z)) {
-            break;
-        }
-
-        for (k, v) in wires.iter() {
-            if initial.contains_key(k) {
-                continue;
-            }
-
-            if initial.contains_key(&v.lhs) && initial.contains_key(&v.rhs) {
-                let lhs = initial.get(&v.lhs).unwrap();
-                let rhs = initial.get(&v.rhs).unwrap();
-                let value = match v.op {
-                    Operation::AND => lhs.bitand(rhs),
-                    Operation::OR => lhs.bitor(rhs),
-                    Operation::XOR => lhs.bitxor(rhs),
-                };
-                initial.insert(k.clone(), value);
-            }
-        }
-    }
-
-    let bytestring = initial
-        .iter()
-        .filter(|&(k, _)| k.starts_with("z"))
-        .sorted()
-        .rev()
-        .map(|(_, v)| format!("{}", v))
-        .join("");
-
-    let res = u64::from_str_radix(&bytestring, 2).unwrap();
-
-    Ok(res.to_string())
-}
-
-#[derive(Debug, PartialEq, Eq)]
-enum Operation {
-    AND,
-    OR,
-    XOR,
-}
-
-#[derive(Debug, PartialEq, Eq)]
-struct Wire {
-    lhs: String,
-    op: Operation,
-    rhs: String,
-}
-
-impl Wire {
-    fn new(lhs: String, op: Operation, rhs: String) -> Self {
-        Wire { lhs, op, rhs }
-    }
-}
-
-fn parse(input: &mut &str) -> winnow::PResult<(HashMap<String, u8>, HashMap<String, Wire>)> {
-    seq!(
-        parse_initial_values,
-        _: multispace1,
-        parse_wires
-    )
-    .parse_next(input)
-}
-
-fn parse_initial_values(input: &mut &str) -> winnow::PResult<HashMap<String, u8>> {
-    separated(
-        1..,
-        separated_pair(alphanumeric1.parse_to(), ": ", dec_uint::<_, u8, _>),
-        line_ending,
-    )
-    .parse_next(input)
-}
-
-fn parse_wires(input: &mut &str) -> winnow::PResult<HashMap<String, Wire>> {
-    // ntg XOR fgs -> mjb
-    separated(
-        1..,
-        separated_pair(separated(1.., alphanumeric1, space1), " -> ", alphanumeric1).map(
-            |(a, b): (Vec<&str>, &str)| {
-                let op = if a[1] == "AND" {
-                    Operation::AND
-                } else if a[1] == "OR" {
-                    Operation::OR
-                } else {
-                    Operation::XOR
-                };
-                (
-                    b.to_string(),
-                    Wire::new(a[0].to_string(), op, a[2].to_string()),
-                )
-            },
-        ),
-        line_ending,
-    )
-    .parse_next(input)
+    Ok(res.into())
 }
 
 #[cfg(test)]
@@ -177,52 +65,4 @@ tnw OR pbm -> gnj";
         assert_eq!("2024", process(&mut input)?);
         Ok(())
     }
-
-    #[test]
-    fn test_init() -> miette::Result<()> {
-        let mut input = "x00: 1\nx01: 0";
-        let expected = HashMap::from_iter(vec![("x00".to_string(), 1), ("x01".to_string(), 0)]);
-        assert_eq!(expected, parse_initial_values(&mut input).unwrap());
-        Ok(())
-    }
-
-    #[test]
-    fn test_parse_wire() -> miette::Result<()> {
-        let mut input = "ntg XOR fgs -> mjb\ny02 OR x01 -> tnw";
-        let expected = HashMap::from_iter(vec![
-            (
-                "mjb".to_string(),
-                Wire::new("ntg".to_string(), Operation::XOR, "fgs".to_string()),
-            ),
-            (
-                "tnw".to_string(),
-                Wire::new("y02".to_string(), Operation::OR, "x01".to_string()),
-            ),
-        ]);
-        assert_eq!(expected, parse_wires(&mut input).unwrap());
-        Ok(())
-    }
-    #[test]
-    fn test_parse() -> miette::Result<()> {
-        let mut input = "x00: 1
-x01: 0
-
-ntg XOR fgs -> mjb
-y02 OR x01 -> tnw";
-        let expected = (
-            HashMap::from_iter(vec![("x00".to_string(), 1), ("x01".to_string(), 0)]),
-            HashMap::from_iter(vec![
-                (
-                    "mjb".to_string(),
-                    Wire::new("ntg".to_string(), Operation::XOR, "fgs".to_string()),
-                ),
-                (
-                    "tnw".to_string(),
-                    Wire::new("y02".to_string(), Operation::OR, "x01".to_string()),
-                ),
-            ]),
-        );
-        assert_eq!(expected, parse(&mut input).unwrap());
-        Ok(())
-    }
 }
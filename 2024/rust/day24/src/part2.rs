@@ -1,17 +1,187 @@
+use std::collections::HashMap;
+
+use aoc_answer::Answer;
+use itertools::Itertools;
+use miette::miette;
+
+use crate::circuit::{parse, Operation, Wire, Wires};
+
+/// The puzzle promises exactly this many pairs of gates had their outputs swapped.
+pub const SWAPPED_PAIRS: usize = 4;
+
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String> {
-    todo!("day24 - part 2");
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let (_, wires) = parse(input)?;
+    let swapped = swapped_wires(&wires);
+    if swapped.len() != 2 * SWAPPED_PAIRS {
+        return Err(miette!(
+            "expected {} swapped wires, found {}: {}",
+            2 * SWAPPED_PAIRS,
+            swapped.len(),
+            swapped.join(",")
+        ));
+    }
+    Ok(swapped.join(",").into())
+}
+
+/// The outputs that break the ripple-carry adder pattern, sorted by name.
+///
+/// Bit 0 is a half adder, `z00 = x00 XOR y00` with carry `x00 AND y00`. Every other bit `i` is a
+/// full adder taking the carry `c` from the bit below:
+///
+/// ```text
+/// p = xi XOR yi    g = xi AND yi
+/// zi = p XOR c     t = p AND c
+/// carry = g OR t
+/// ```
+///
+/// and the last carry is the highest `z`. Swapping two outputs makes one or both of the gates
+/// drive a wire that is used in the wrong way, which is what the checks below look for.
+pub fn swapped_wires(wires: &Wires) -> Vec<String> {
+    let last_z = wires
+        .keys()
+        .filter(|k| k.starts_with('z'))
+        .max()
+        .cloned()
+        .unwrap_or_default();
+
+    // The gates every wire feeds into.
+    let mut consumers: HashMap<&str, Vec<Operation>> = HashMap::new();
+    for wire in wires.values() {
+        consumers.entry(&wire.lhs).or_default().push(wire.op);
+        consumers.entry(&wire.rhs).or_default().push(wire.op);
+    }
+    let feeds = |out: &str, op: Operation| consumers.get(out).is_some_and(|ops| ops.contains(&op));
+
+    wires
+        .iter()
+        .filter(|&(out, wire)| {
+            let is_z = out.starts_with('z');
+            let from_inputs = is_input(&wire.lhs) && is_input(&wire.rhs);
+            let first_bit = from_inputs && is_first_bit(wire);
+            match wire.op {
+                _ if *out == last_z => wire.op != Operation::OR,
+                Operation::XOR if from_inputs => {
+                    // The half adder writes z00 directly, every other `p` feeds a sum and carry.
+                    if first_bit {
+                        out != "z00"
+                    } else {
+                        is_z || !feeds(out, Operation::XOR)
+                    }
+                }
+                Operation::XOR => !is_z,
+                _ if is_z => true,
+                Operation::AND if first_bit => !feeds(out, Operation::XOR),
+                Operation::AND => !feeds(out, Operation::OR),
+                Operation::OR => !feeds(out, Operation::XOR),
+            }
+        })
+        .map(|(out, _)| out.clone())
+        .sorted()
+        .collect()
+}
+
+fn is_input(wire: &str) -> bool {
+    wire.starts_with('x') || wire.starts_with('y')
+}
+
+fn is_first_bit(wire: &Wire) -> bool {
+    wire.lhs.ends_with("00") && wire.rhs.ends_with("00")
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
+    use rstest::rstest;
+
+    /// A correct `bits`-bit ripple-carry adder, as puzzle input with every input bit set to 0.
+    ///
+    /// Gates are named `p`, `g`, `t` and `c` after the pattern in [`swapped_wires`], so they can
+    /// never be mistaken for inputs or outputs.
+    pub(crate) fn adder(bits: usize) -> String {
+        let mut values = vec![];
+        let mut gates = vec![];
+        for i in 0..bits {
+            values.push(format!("x{i:02}: 0"));
+            values.push(format!("y{i:02}: 0"));
+            if i == 0 {
+                gates.push("x00 XOR y00 -> z00".to_string());
+                gates.push("x00 AND y00 -> c00".to_string());
+                continue;
+            }
+            let carry = format!("c{:02}", i - 1);
+            let out = if i == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            gates.push(format!("x{i:02} XOR y{i:02} -> p{i:02}"));
+            gates.push(format!("y{i:02} AND x{i:02} -> g{i:02}"));
+            gates.push(format!("p{i:02} XOR {carry} -> z{i:02}"));
+            gates.push(format!("{carry} AND p{i:02} -> t{i:02}"));
+            gates.push(format!("g{i:02} OR t{i:02} -> {out}"));
+        }
+        format!("{}\n\n{}", values.join("\n"), gates.join("\n"))
+    }
+
+    /// Swap the outputs of the gates driving `a` and `b`.
+    pub(crate) fn swap(input: &str, a: &str, b: &str) -> String {
+        input
+            .lines()
+            .map(|line| {
+                if let Some(gate) = line.strip_suffix(&format!("-> {a}")) {
+                    format!("{gate}-> {b}")
+                } else if let Some(gate) = line.strip_suffix(&format!("-> {b}")) {
+                    format!("{gate}-> {a}")
+                } else {
+                    line.to_string()
+                }
+            })
+            .join("\n")
+    }
+
+    fn swapped(input: &str) -> miette::Result<String> {
+        let (_, wires) = parse(input)?;
+        Ok(swapped_wires(&wires).join(","))
+    }
 
     #[test]
-    fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+    fn test_correct_adder() -> miette::Result<()> {
+        assert_eq!("", swapped(&adder(8))?);
+        assert!(process(&adder(8)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_needs_four_pairs() {
+        let err = process(&swap(&adder(8), "z03", "c03")).unwrap_err();
+        assert_eq!(
+            "expected 8 swapped wires, found 2: c03,z03",
+            err.to_string()
+        );
+    }
+
+    #[rstest]
+    #[case(&[("z03", "c03")], "c03,z03")]
+    #[case(&[("z05", "t05")], "t05,z05")]
+    #[case(&[("p04", "g04")], "g04,p04")]
+    #[case(&[("c02", "t02")], "c02,t02")]
+    #[case(&[("z01", "p01")], "p01,z01")]
+    #[case(&[("z00", "c00")], "c00,z00")]
+    #[case(&[("z08", "z07")], "z07,z08")]
+    #[case(
+        &[("z02", "c02"), ("p04", "g04"), ("z06", "t06"), ("c05", "t05")],
+        "c02,c05,g04,p04,t05,t06,z02,z06"
+    )]
+    fn test_swaps(#[case] swaps: &[(&str, &str)], #[case] expected: &str) -> miette::Result<()> {
+        let mut input = adder(8);
+        for (a, b) in swaps {
+            input = swap(&input, a, b);
+        }
+        assert_eq!(expected, swapped(&input)?);
+        if swaps.len() == SWAPPED_PAIRS {
+            assert_eq!(expected, process(&input)?);
+        }
         Ok(())
     }
 }
@@ -1,3 +1,5 @@
+use aoc_answer::Answer;
+use aoc_parse::ParseError;
 use itertools::Itertools;
 use miette::miette;
 use winnow::{
@@ -7,49 +9,76 @@ use winnow::{
 };
 
 #[tracing::instrument]
-pub fn process(input: &mut &str) -> miette::Result<String> {
-    let parsed = parse(input).map_err(|e| miette!("should be able to parse AoC {}", e))?;
-    let locks = parsed
-        .iter()
-        .filter(|&v| v[0] == vec![1, 1, 1, 1, 1])
-        .map(|lock| {
-            lock.iter().fold(vec![0, 0, 0, 0, 0], |acc: Vec<u8>, row| {
-                acc.iter().zip(row).map(|(a, b)| a + b).collect()
-            })
-        })
-        .collect::<Vec<Vec<u8>>>();
+pub fn process(input: &mut &str) -> miette::Result<Answer> {
+    let schematics = Schematics::new(parse(input)?)?;
 
-    let keys = parsed
+    let res = schematics
+        .locks
         .iter()
-        .filter(|&v| v[0] == vec![0, 0, 0, 0, 0])
-        .map(|lock| {
-            lock.iter().fold(vec![0, 0, 0, 0, 0], |acc: Vec<u8>, row| {
-                acc.iter().zip(row).map(|(a, b)| a + b).collect()
-            })
-        })
-        .collect::<Vec<Vec<u8>>>();
+        .cartesian_product(schematics.keys.iter())
+        .filter(|&(lock, key)| lock & key == 0)
+        .count();
 
-    let res = locks
-        .iter()
-        .cartesian_product(keys.iter())
-        .filter_map(|(l, k)| {
-            let cnt = l
+    Ok(res.into())
+}
+
+/// Locks and keys packed one cell per bit, row by row, so a key fits a lock when no bit is set
+/// in both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Schematics {
+    width: usize,
+    height: usize,
+    locks: Vec<u64>,
+    keys: Vec<u64>,
+}
+
+impl Schematics {
+    /// Sort parsed schematics into locks (top row filled) and keys (top row empty), taking the
+    /// size from the first one.
+    fn new(parsed: Vec<Vec<Vec<u8>>>) -> miette::Result<Self> {
+        let height = parsed.first().map_or(0, |rows| rows.len());
+        let width = parsed
+            .first()
+            .and_then(|rows| rows.first())
+            .map_or(0, |row| row.len());
+        if width * height > u64::BITS as usize {
+            return Err(miette!(
+                "{width}x{height} schematics do not fit in a {}-bit mask",
+                u64::BITS
+            ));
+        }
+
+        let mut schematics = Self {
+            width,
+            height,
+            locks: vec![],
+            keys: vec![],
+        };
+        for (i, rows) in parsed.iter().enumerate() {
+            if rows.len() != height || rows.iter().any(|row| row.len() != width) {
+                return Err(miette!(
+                    "schematic {} is not {width}x{height} like the first one",
+                    i + 1
+                ));
+            }
+            let mask = rows
                 .iter()
-                .zip(k.iter())
-                .map(|(a, b)| a + b)
-                .collect::<Vec<u8>>();
-            match cnt.iter().all(|&v| v <= 7) {
-                true => Some((l, k)),
-                false => None,
+                .flatten()
+                .fold(0u64, |mask, &cell| mask << 1 | cell as u64);
+            if rows[0].iter().all(|&c| c == 1) {
+                schematics.locks.push(mask);
+            } else if rows[0].iter().all(|&c| c == 0) {
+                schematics.keys.push(mask);
+            } else {
+                return Err(miette!("schematic {} is neither a lock nor a key", i + 1));
             }
-        })
-        .count();
-
-    Ok(res.to_string())
+        }
+        Ok(schematics)
+    }
 }
 
-fn parse(input: &mut &str) -> winnow::PResult<Vec<Vec<Vec<u8>>>> {
-    separated(1.., parse_rows, multispace1).parse_next(input)
+fn parse(input: &str) -> Result<Vec<Vec<Vec<u8>>>, ParseError> {
+    aoc_parse::parse(input, separated(1.., parse_rows, multispace1))
 }
 
 fn parse_row(input: &mut &str) -> winnow::PResult<Vec<u8>> {
@@ -73,6 +102,7 @@ fn parse_rows(input: &mut &str) -> winnow::PResult<Vec<Vec<u8>>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_process() -> miette::Result<()> {
@@ -119,6 +149,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_schematics() -> miette::Result<()> {
+        let input = "###
+.#.
+
+...
+#.#
+
+...
+.##";
+        let schematics = Schematics::new(parse(input)?)?;
+        assert_eq!(3, schematics.width);
+        assert_eq!(2, schematics.height);
+        assert_eq!(vec![0b111_010], schematics.locks);
+        assert_eq!(vec![0b000_101, 0b000_011], schematics.keys);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("###\n.#.\n\n...\n#", "schematic 2 is not 3x2 like the first one")]
+    #[case("###\n.#.\n\n.#.\n###", "schematic 2 is neither a lock nor a key")]
+    fn test_schematics_errors(#[case] input: &str, #[case] expected: &str) -> miette::Result<()> {
+        let err = Schematics::new(parse(input)?).unwrap_err();
+        assert_eq!(expected, err.to_string());
+        Ok(())
+    }
+
     #[test]
     fn test_lock() -> miette::Result<()> {
         let mut input = "#####
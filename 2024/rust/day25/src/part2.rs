@@ -1,17 +1,38 @@
+use aoc_answer::{db::AnswerDb, Answer};
+
+use crate::summary::Summary;
+
+/// There is no puzzle for the last star; it is awarded once every other star is collected, so
+/// part 2 reports the stars recorded in the answers file instead of reading the input.
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String> {
-    todo!("day25 - part 2");
+pub fn process(_input: &str) -> miette::Result<Answer> {
+    let db = AnswerDb::load(&AnswerDb::default_path(&aoc_input::input_dir()))?;
+    Ok(report(&db))
+}
+
+fn report(db: &AnswerDb) -> Answer {
+    Answer::art(Summary::new(db).to_string())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_report() {
+        let mut db = AnswerDb::new();
+        db.insert(3, 1, Answer::from(161));
+        let answer = report(&db);
+        assert!(answer.is_multiline());
+        assert!(answer
+            .to_string()
+            .starts_with("Advent of Code 2024: 1/50 stars\n01    02    03 *"));
+    }
+
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        let answer = process("")?;
+        assert!(answer.to_string().starts_with("Advent of Code 2024: "));
         Ok(())
     }
 }
@@ -0,0 +1,87 @@
+use std::fmt;
+
+use aoc_answer::db::AnswerDb;
+use itertools::Itertools;
+
+/// The stars collected on every day, counting a part as solved once its answer is recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    stars: [u8; 25],
+}
+
+impl Summary {
+    pub fn new(db: &AnswerDb) -> Self {
+        let mut stars = [0; 25];
+        for (day, stars) in (1..=25).zip(stars.iter_mut()) {
+            *stars = (1..=2).filter(|&part| db.get(day, part).is_some()).count() as u8;
+        }
+        // The final star is not a puzzle answer, it comes with all the others.
+        stars[24] = stars[24].min(1);
+        if stars[24] == 1 && stars[..24].iter().all(|&s| s == 2) {
+            stars[24] = 2;
+        }
+        Self { stars }
+    }
+
+    pub fn total(&self) -> usize {
+        self.stars.iter().map(|&s| s as usize).sum()
+    }
+}
+
+/// A header with the total, then the calendar five days to a row:
+///
+/// ```text
+/// Advent of Code 2024: 3/50 stars
+/// 01 ** 02 *  03    04    05
+/// ```
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Advent of Code 2024: {}/50 stars", self.total())?;
+        for week in &self.stars.iter().enumerate().chunks(5) {
+            let days = week
+                .map(|(i, &stars)| format!("{:02} {:<2}", i + 1, "*".repeat(stars as usize)))
+                .join(" ");
+            write!(f, "\n{}", days.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_answer::Answer;
+
+    #[test]
+    fn test_summary() {
+        let mut db = AnswerDb::new();
+        db.insert(1, 1, Answer::from(11));
+        db.insert(1, 2, Answer::from(31));
+        db.insert(2, 1, Answer::from(2));
+        db.insert(25, 1, Answer::from(3));
+
+        let summary = Summary::new(&db);
+        assert_eq!(4, summary.total());
+        assert_eq!(
+            "Advent of Code 2024: 4/50 stars
+01 ** 02 *  03    04    05
+06    07    08    09    10
+11    12    13    14    15
+16    17    18    19    20
+21    22    23    24    25 *",
+            summary.to_string()
+        );
+    }
+
+    #[test]
+    fn test_full_season() {
+        let mut db = AnswerDb::new();
+        for day in 1..=25 {
+            db.insert(day, 1, Answer::from(day));
+            if day < 25 {
+                db.insert(day, 2, Answer::from(day));
+            }
+        }
+        assert_eq!(50, Summary::new(&db).total());
+    }
+}